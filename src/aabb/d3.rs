@@ -1,10 +1,13 @@
 use crate::{Float, Vector3};
-use std::mem::swap;
+use std::{
+    fmt::{self, Debug, Display},
+    mem::swap,
+};
 
 type V3<F> = Vector3<F>;
 
 /// 3D Axis aligned bounded box.
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct Aabb3<F: Float> {
     /// Min point.
     pub min: V3<F>,
@@ -29,9 +32,9 @@ impl<F: Float> Aabb3<F> {
         Self::from_min_max(self.min + delta, self.max + delta)
     }
 
-    /// Checks if `Aabb` is right, i.e. `max` > `min`.
+    /// Checks if `Aabb` is right, i.e. `max` > `min` for every component.
     pub fn is_right(&self) -> bool {
-        self.max > self.min
+        self.max.x > self.min.x && self.max.y > self.min.y && self.max.z > self.min.z
     }
 
     /// Swaps `min`, `max`
@@ -44,14 +47,186 @@ impl<F: Float> Aabb3<F> {
         Self::from_min_max(self.max, self.min)
     }
 
+    /// Returns the size of the bounding box along each axis.
+    pub fn size(&self) -> V3<F> {
+        self.max - self.min
+    }
+
+    /// Returns the center of the bounding box.
+    pub fn center(&self) -> V3<F> {
+        (self.min + self.max) / F::TWO
+    }
+
+    /// Returns the corner of the bounding box with index `i`. Bits `0`, `1` and `2` of the
+    /// index select `max` instead of `min` for `x`, `y` and `z` components respectively,
+    /// e.g. corner `0` is `min` and corner `7` is `max`.
+    /// # Panics
+    /// If `i` is not in `0..8` range.
+    pub fn corner(&self, i: usize) -> V3<F> {
+        assert!(i < 8, "Corner index must be in 0..8 range, got: {i}");
+
+        let pick = |bit: usize, min: F, max: F| if i & bit == 0 { min } else { max };
+        V3::new(
+            pick(1, self.min.x, self.max.x),
+            pick(2, self.min.y, self.max.y),
+            pick(4, self.min.z, self.max.z),
+        )
+    }
+
+    /// Returns all corners of the bounding box. See [`Self::corner`] for the order.
+    pub fn corners(&self) -> [V3<F>; 8] {
+        std::array::from_fn(|i| self.corner(i))
+    }
+
+    /// Returns the edges of the bounding box as pairs of corner indices. See [`Self::corner`].
+    pub const fn edges(&self) -> [(usize, usize); 12] {
+        [
+            (0, 1),
+            (2, 3),
+            (4, 5),
+            (6, 7),
+            (0, 2),
+            (1, 3),
+            (4, 6),
+            (5, 7),
+            (0, 4),
+            (1, 5),
+            (2, 6),
+            (3, 7),
+        ]
+    }
+
+    /// Returns the center and the radius of the smallest sphere enclosing the bounding box.
+    /// Radius is equal to half of the diagonal length.
+    pub fn bounding_sphere(&self) -> (V3<F>, F) {
+        (self.center(), self.size().magnitude() / F::TWO)
+    }
+
+    /// Returns the radius of the largest sphere that fits inside of the bounding box, i.e. half
+    /// of the smallest extent.
+    pub fn inscribed_sphere_radius(&self) -> F {
+        self.size().min_element() / F::TWO
+    }
+
     /// Returns the volume of the bounding box.
     pub fn volume(&self) -> F {
         let dv = self.max - self.min;
         dv.product()
     }
 
-    /// Checks if `Aabb` contains a point.
+    /// Checks if `Aabb` contains a point. Points on the boundary are considered contained, see
+    /// [`Self::contains_strict`].
     pub fn contains(&self, point: V3<F>) -> bool {
-        point >= self.min && point <= self.max
+        (0..3).all(|i| point[i] >= self.min[i] && point[i] <= self.max[i])
+    }
+
+    /// Checks if a point is strictly inside of the `Aabb`, i.e. not on its boundary.
+    pub fn contains_strict(&self, point: V3<F>) -> bool {
+        (0..3).all(|i| point[i] > self.min[i] && point[i] < self.max[i])
+    }
+
+    /// Returns the point on the surface of the `Aabb` closest to `point`. Unlike clamping, points
+    /// inside of the box are projected onto the nearest face.
+    pub fn closest_point_on_surface(&self, point: V3<F>) -> V3<F> {
+        let mut closest = point.max(self.min).min(self.max);
+        if closest != point {
+            return closest;
+        }
+
+        let (mut axis, mut value, mut distance) = (0, self.min.x, point.x - self.min.x);
+        for i in 0..3 {
+            for face in [self.min[i], self.max[i]] {
+                if (point[i] - face).abs() < distance {
+                    (axis, value, distance) = (i, face, (point[i] - face).abs());
+                }
+            }
+        }
+
+        closest[axis] = value;
+        closest
+    }
+
+    /// Returns the smallest `Aabb` enclosing both `self` and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_min_max(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// Returns the smallest `Aabb` enclosing all `boxes` or `None` if there are none.
+    pub fn union_all(boxes: impl IntoIterator<Item = Self>) -> Option<Self> {
+        boxes.into_iter().reduce(|acc, b| acc.union(&b))
+    }
+
+    /// Linearly interpolates `min` and `max` points separately.
+    /// The result is only meaningful if both boxes are right. See [`Self::is_right`].
+    pub fn lerp(self, other: Self, t: F) -> Self {
+        Self::from_min_max(self.min.lerp(other.min, t), self.max.lerp(other.max, t))
+    }
+
+    /// Grows `Aabb` in place to enclose `other`. See [`Self::union`].
+    pub fn merge(&mut self, other: &Self) {
+        *self = self.union(other);
+    }
+
+    /// Clips segment `ab` to the bounding box, returning the part of the segment inside of it
+    /// or `None` if the segment is entirely outside.
+    pub fn clip_segment(&self, a: V3<F>, b: V3<F>) -> Option<(V3<F>, V3<F>)> {
+        let dir = b - a;
+        let (t0, t1) = self.clip_line(a, dir, F::ZERO, F::ONE)?;
+
+        Some((a + dir * t0, a + dir * t1))
+    }
+
+    /// Checks if segment `ab` touches the bounding box.
+    pub fn intersects_segment(&self, a: V3<F>, b: V3<F>) -> bool {
+        self.clip_line(a, b - a, F::ZERO, F::ONE).is_some()
+    }
+
+    /// Slab test for the line `origin + dir * t`, narrowing `[t0, t1]` interval to the part of
+    /// the line inside the box.
+    fn clip_line(&self, origin: V3<F>, dir: V3<F>, mut t0: F, mut t1: F) -> Option<(F, F)> {
+        for i in 0..3 {
+            if dir[i].abs() < F::EPSILON {
+                if origin[i] < self.min[i] || origin[i] > self.max[i] {
+                    return None;
+                }
+            } else {
+                let mut near = (self.min[i] - origin[i]) / dir[i];
+                let mut far = (self.max[i] - origin[i]) / dir[i];
+                if near > far {
+                    swap(&mut near, &mut far);
+                }
+
+                t0 = t0.max(near);
+                t1 = t1.min(far);
+                if t0 > t1 {
+                    return None;
+                }
+            }
+        }
+
+        Some((t0, t1))
+    }
+}
+
+impl<F: Float> Debug for Aabb3<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Aabb3")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("size", &self.size())
+            .field("center", &self.center())
+            .finish()
+    }
+}
+
+impl<F: Float> Display for Aabb3<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max, size) = (self.min, self.max, self.size());
+
+        write!(
+            f,
+            "Aabb3 {{ min: ({}, {}, {}), max: ({}, {}, {}), size: ({}, {}, {}) }}",
+            min.x, min.y, min.z, max.x, max.y, max.z, size.x, size.y, size.z
+        )
     }
 }
@@ -2,7 +2,7 @@ use crate::Float;
 use std::{
     fmt::{self, Debug, Display},
     marker::PhantomData,
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Div, Mul, Rem},
 };
 
 /// Radians marker type.
@@ -28,6 +28,26 @@ pub type Angled<M> = Angle<f64, M>;
 #[repr(transparent)]
 pub struct Angle<F: Float, M: Measure>(pub F, PhantomData<M>);
 
+impl<F: Float, M: Measure> Angle<F, M> {
+    /// Creates new angle from a raw value in measure `M`.
+    #[inline]
+    pub const fn new(value: F) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Converts the angle to radians.
+    #[inline]
+    pub fn into_radians(self) -> Angle<F, Rad> {
+        Angle::new(self.0 * M::to_radians_factor::<F>())
+    }
+
+    /// Converts the angle to degrees.
+    #[inline]
+    pub fn into_degrees(self) -> Angle<F, Deg> {
+        Angle::new(self.0 * M::to_degrees_factor::<F>())
+    }
+}
+
 impl<F: Float, M: Measure> Debug for Angle<F, M> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self.0, f)
@@ -75,8 +95,91 @@ impl<F: Float, M: Measure> DerefMut for Angle<F, M> {
     }
 }
 
+impl<F: Float, M: Measure> Mul<F> for Angle<F, M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        Self::new(self.0 * rhs)
+    }
+}
+
+impl<F: Float, M: Measure> Div<F> for Angle<F, M> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        Self::new(self.0 / rhs)
+    }
+}
+
+impl<F: Float, M: Measure> Div for Angle<F, M> {
+    type Output = F;
+
+    /// Returns dimensionless ratio of two angles.
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.0 / rhs.0
+    }
+}
+
+impl<F: Float, M: Measure> Rem<F> for Angle<F, M> {
+    type Output = Self;
+
+    /// Wraps the angle to `[0, rhs)` range if `rhs` is positive or `(rhs, 0]` otherwise.
+    /// Unlike `%` on floats, the result has the sign of `rhs`, so `-90° % 360°` is `270°`.
+    #[inline]
+    fn rem(self, rhs: F) -> Self::Output {
+        Self::new(self.0 - rhs * (self.0 / rhs).floor())
+    }
+}
+
+impl<F: Float, M: Measure> Rem for Angle<F, M> {
+    type Output = Self;
+
+    /// Wraps the angle by another angle, same as `self % rhs.0`.
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        self % rhs.0
+    }
+}
+
 /// Helper trait to distinguish between radians and degrees.
-pub trait Measure: private::Sealed {}
+pub trait Measure: private::Sealed {
+    /// Unit suffix appended to a value when displaying it.
+    const SUFFIX: &'static str;
 
-impl Measure for Rad {}
-impl Measure for Deg {}
+    /// Returns the number of radians in one unit of this measure.
+    fn to_radians_factor<F: Float>() -> F;
+
+    /// Returns the number of degrees in one unit of this measure.
+    fn to_degrees_factor<F: Float>() -> F;
+}
+
+impl Measure for Rad {
+    const SUFFIX: &'static str = " rad";
+
+    #[inline]
+    fn to_radians_factor<F: Float>() -> F {
+        F::ONE
+    }
+
+    #[inline]
+    fn to_degrees_factor<F: Float>() -> F {
+        F::ONE.to_degrees()
+    }
+}
+
+impl Measure for Deg {
+    const SUFFIX: &'static str = "°";
+
+    #[inline]
+    fn to_radians_factor<F: Float>() -> F {
+        F::ONE.to_radians()
+    }
+
+    #[inline]
+    fn to_degrees_factor<F: Float>() -> F {
+        F::ONE
+    }
+}
@@ -1,3 +1,20 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_scalar_lhs_mul {
+    ($s:ident, $($float:ty),*) => {
+        $(
+            impl core::ops::Mul<$s<$float>> for $float {
+                type Output = $s<$float>;
+
+                #[inline]
+                fn mul(self, rhs: $s<$float>) -> $s<$float> {
+                    rhs * self
+                }
+            }
+        )*
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_planar_ops {
@@ -19,6 +36,12 @@ macro_rules! __impl_planar_ops {
                 }
             }
 
+            /// Creates new with all components equal to `val`. Same as [`Self::same`].
+            #[inline]
+            pub const fn splat(val: F) -> Self {
+                Self::same(val)
+            }
+
             /// Splits into components.
             #[inline]
             pub const fn into_parts(self) -> ($($t),*) {
@@ -56,7 +79,7 @@ macro_rules! __impl_planar_ops {
             /// Returns squared magnitude.
             #[inline]
             pub fn sqr_magnitude(&self) -> F {
-                $(self.$f * self.$f +)* F::ZERO
+                $(self.$f.square() +)* F::ZERO
             }
 
             /// Returns magnitude.
@@ -65,6 +88,14 @@ macro_rules! __impl_planar_ops {
                 self.sqr_magnitude().sqrt()
             }
 
+            /// Returns `L^p` norm, i.e. `(|x|^p + |y|^p + ...)^(1 / p)`.
+            /// `p` = `1` gives manhattan length, `p` = `2` gives [`Self::magnitude`], large `p`
+            /// approaches the maximum absolute component.
+            #[inline]
+            pub fn norm(&self, p: F) -> F {
+                ($(self.$f.abs().powf(p) +)* F::ZERO).powf(F::ONE / p)
+            }
+
             /// Returns maximum component.
             #[inline]
             pub fn max(&self, other: Self) -> Self {
@@ -95,6 +126,39 @@ macro_rules! __impl_planar_ops {
             //     }
             // }
 
+            /// Returns a copy where every component is replaced with its sign, i.e. `1` or `-1`.
+            /// Zero components become `1` or `-1` depending on the sign of zero, see
+            /// [`Self::signum_or_zero`].
+            #[inline]
+            pub fn signum(self) -> Self {
+                Self {
+                    $(
+                        $f: self.$f.signum()
+                    ),*
+                }
+            }
+
+            /// Returns a copy where every component is replaced with its sign, i.e. `1`, `-1` or
+            /// `0` for zero components.
+            #[inline]
+            pub fn signum_or_zero(self) -> Self {
+                Self {
+                    $(
+                        $f: if self.$f == F::ZERO { F::ZERO } else { self.$f.signum() }
+                    ),*
+                }
+            }
+
+            /// Returns a copy where all components are clamped between `0` and `1`.
+            #[inline]
+            pub fn saturate(self) -> Self {
+                Self {
+                    $(
+                        $f: self.$f.clamp(F::ZERO, F::ONE)
+                    ),*
+                }
+            }
+
             /// Returns a copy where all components are posivive.
             #[inline]
             pub fn abs(self) -> Self {
@@ -112,6 +176,37 @@ macro_rules! __impl_planar_ops {
                     self.$f.abs() < F::EPSILON &&
                 )* true
             }
+
+            /// Divides components of `self` by components of `other`.
+            /// Returns `None` if any component of `other` is zero with regard to `EPSILON`.
+            #[inline]
+            pub fn try_div_components(self, other: Self) -> Option<Self> {
+                if $(other.$f.abs() < F::EPSILON ||)* false {
+                    None
+                } else {
+                    Some(self / other)
+                }
+            }
+        }
+
+        impl<F: Float> $crate::Step for $s<F> {
+            #[inline]
+            fn step(self, edge: Self) -> Self {
+                Self {
+                    $(
+                        $f: self.$f.step(edge.$f)
+                    ),*
+                }
+            }
+
+            #[inline]
+            fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+                Self {
+                    $(
+                        $f: self.$f.smoothstep(edge0.$f, edge1.$f)
+                    ),*
+                }
+            }
         }
 
         impl<F: Float> core::convert::From<($($t),*)> for $s<F> {
@@ -222,6 +317,52 @@ macro_rules! __impl_planar_ops {
             }
         }
 
+        /// Multiplies vectors component-wise.
+        impl<F: Float> core::ops::Mul for $s<F> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self {
+                    $(
+                        $f: self.$f * rhs.$f
+                    ),*
+                }
+            }
+        }
+
+        impl<F: Float> core::ops::MulAssign for $s<F> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                $(
+                    self.$f = self.$f * rhs.$f
+                );*
+            }
+        }
+
+        /// Divides vectors component-wise.
+        impl<F: Float> core::ops::Div for $s<F> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                Self {
+                    $(
+                        $f: self.$f / rhs.$f
+                    ),*
+                }
+            }
+        }
+
+        impl<F: Float> core::ops::DivAssign for $s<F> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                $(
+                    self.$f = self.$f / rhs.$f
+                );*
+            }
+        }
+
         impl<F: Float> core::ops::Neg for $s<F> {
             type Output = Self;
 
@@ -234,13 +375,32 @@ macro_rules! __impl_planar_ops {
                 }
             }
         }
+
+        $crate::__impl_scalar_lhs_mul!($s, f32, f64);
     };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_mat_ops {
-    ($mat:ident, $rowtype:ident, $dim:expr, $($r:ident),*) => {
+    ($mat:ident, $rowtype:ident, $dim:expr, $($r:ident: $unit:ident),*) => {
+        impl<F: Float> $mat<F> {
+            /// Matrix with all elements equal to `0`.
+            pub const ZERO: Self = Self {
+                $($r: $rowtype::ZERO),*
+            };
+
+            /// Matrix with all elements equal to `1`.
+            pub const ONE: Self = Self {
+                $($r: $rowtype::ONE),*
+            };
+
+            /// Identity matrix with diagonal elements equal to `1` and `0` for every other.
+            pub const IDENTITY: Self = Self {
+                $($r: $rowtype::$unit),*
+            };
+        }
+
         impl<F: Float> $mat<F> {
             /// Inverses matrix in place.
             /// # Panics
@@ -295,6 +455,81 @@ macro_rules! __impl_mat_ops {
             }
 
 
+            /// Computes the hadamard(element-wise) product of two matrices.
+            pub fn hadamard(&self, other: &Self) -> Self {
+                let mut out = *self;
+
+                for i in 0..$dim {
+                    for j in 0..$dim {
+                        out[i][j] *= other[i][j];
+                    }
+                }
+
+                out
+            }
+
+            /// Linearly interpolates between two matrices element-wise.
+            /// # Warning
+            /// Element-wise interpolation is only correct for scale and translation. For matrices
+            /// containing rotations, prefer decomposing them into translation, rotation and scale
+            /// and slerping the rotations.
+            pub fn lerp(&self, other: &Self, t: F) -> Self {
+                *self + (*other - *self) * t
+            }
+
+            /// Returns a copy of the matrix with `f` applied to every element.
+            pub fn map(&self, f: impl Fn(F) -> F) -> Self {
+                let mut out = *self;
+                for i in 0..$dim {
+                    for j in 0..$dim {
+                        out[i][j] = f(self[i][j]);
+                    }
+                }
+
+                out
+            }
+
+            /// Clamps every element between corresponding elements of `min` and `max`.
+            /// This is meant for matrices holding arbitrary data, clamping a transform doesn't
+            /// produce a meaningful transform.
+            /// # Panics
+            /// If any element of `min` is greater than the corresponding element of `max`.
+            pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+                let mut out = *self;
+                for i in 0..$dim {
+                    for j in 0..$dim {
+                        out[i][j] = self[i][j].clamp(min[i][j], max[i][j]);
+                    }
+                }
+
+                out
+            }
+
+            /// Checks if the matrix is equal to its transpose with regard to `epsilon`.
+            pub fn is_symmetric(&self, epsilon: F) -> bool {
+                for i in 0..$dim {
+                    for j in i + 1..$dim {
+                        if (self[i][j] - self[j][i]).abs() >= epsilon {
+                            return false;
+                        }
+                    }
+                }
+
+                true
+            }
+
+            /// Returns the symmetric part of the matrix, i.e. `(M + Mᵀ) / 2`.
+            pub fn symmetrized(&self) -> Self {
+                (*self + self.transposed()) / F::TWO
+            }
+
+            /// Transposes matrix in place and returns it to allow chaining. See `transpose`.
+            #[inline]
+            pub fn transpose_in_place(&mut self) -> &mut Self {
+                self.transpose();
+                self
+            }
+
             /// Swaps two rows in place.
             pub fn swap_rows(&mut self, i: usize, j: usize) {
                 assert!(
@@ -309,17 +544,26 @@ macro_rules! __impl_mat_ops {
             }
 
             /// Turns matrix to its row echelon form using gaussian elimination.
-            pub fn to_row_echelon(&mut self) {
+            /// Returns the number of row swaps performed, each of them flips the sign of the
+            /// determinant.
+            pub fn to_row_echelon(&mut self) -> usize {
                 let (mut h, mut k) = (0, 0);
+                let mut swaps = 0;
 
                 while h < $dim && k < $dim {
-                    let i_max = self.column(k + 1).abs().max_index().max(h);
+                    let mut i_max = h;
+                    for i in (h + 1)..$dim {
+                        if self[i][k].abs() > self[i_max][k].abs() {
+                            i_max = i;
+                        }
+                    }
 
                     if self[i_max][k].abs() < F::EPSILON {
                         k += 1;
                     } else {
                         if h != i_max {
                             self.swap_rows(h + 1, i_max + 1);
+                            swaps += 1;
                         }
 
                         for i in (h + 1)..$dim {
@@ -337,6 +581,8 @@ macro_rules! __impl_mat_ops {
                         k += 1;
                     }
                 }
+
+                swaps
             }
 
             fn row_echelon_reduced(&mut self, adjacent: &mut Self) {
@@ -475,6 +721,8 @@ macro_rules! __impl_mat_ops {
             }
         }
 
+        $crate::__impl_scalar_lhs_mul!($mat, f32, f64);
+
         impl<F: Float> core::ops::Div<F> for $mat<F> {
             type Output = Self;
 
@@ -496,6 +744,31 @@ macro_rules! __impl_mat_ops {
                 );*
             }
         }
+
+        impl<F: Float> core::convert::From<[[F; $dim]; $dim]> for $mat<F> {
+            /// Converts nested array of rows to a matrix.
+            #[inline]
+            fn from([$($r),*]: [[F; $dim]; $dim]) -> Self {
+                Self {
+                    $($r: $rowtype::from_array($r)),*
+                }
+            }
+        }
+
+        impl<F: Float> core::convert::TryFrom<&[F]> for $mat<F> {
+            type Error = core::array::TryFromSliceError;
+
+            /// Converts flat slice of elements in row-major order to a matrix.
+            /// Fails if the length of the slice is not equal to the number of elements.
+            #[inline]
+            fn try_from(slice: &[F]) -> Result<Self, Self::Error> {
+                let array: [F; $dim * $dim] = slice.try_into()?;
+
+                Ok(Self::from(core::array::from_fn::<_, $dim, _>(|i| {
+                    core::array::from_fn(|j| array[i * $dim + j])
+                })))
+            }
+        }
     };
 }
 
@@ -546,6 +819,48 @@ macro_rules! __impl_vec_ops {
                 }
             }
 
+            /// Returns normalized copy of the vector or `None` if its magnitude is zero with
+            /// regard to `EPSILON`. See [`Self::normalized`].
+            #[inline]
+            pub fn try_normalized(&self) -> Option<Self> {
+                let mag = self.magnitude();
+
+                if mag < F::EPSILON {
+                    None
+                } else {
+                    Some(*self / mag)
+                }
+            }
+
+            /// Returns normalized copy of the vector or `fallback` if its magnitude is zero with
+            /// regard to `EPSILON`. See [`Self::try_normalized`].
+            #[inline]
+            pub fn normalized_or(self, fallback: Self) -> Self {
+                self.try_normalized().unwrap_or(fallback)
+            }
+
+            /// Normalizes vector and returns its magnitude before normalization.
+            /// See [`Self::normalize`].
+            #[inline]
+            pub fn normalize_and_length(&mut self) -> F {
+                let mag = self.magnitude();
+
+                $(
+                    self.$c /= mag
+                );*;
+
+                mag
+            }
+
+            /// Returns normalized copy of the vector and its magnitude.
+            /// See [`Self::normalized`].
+            #[inline]
+            pub fn direction_and_length(&self) -> (Self, F) {
+                let mag = self.magnitude();
+
+                (*self / mag, mag)
+            }
+
             /// Converts the vector to an array.
             #[inline]
             pub fn to_array(&self) -> [F; $dim + 1] {
@@ -558,6 +873,35 @@ macro_rules! __impl_vec_ops {
                 unsafe { std::mem::transmute_copy(&array) }
             }
 
+            /// Returns raw bit representations of the components, e.g. to implement exact
+            /// hashing. See [`Float::to_bits`].
+            #[inline]
+            pub fn to_bits_array(&self) -> [u64; $dim + 1] {
+                [$(self.$c.to_bits()),*]
+            }
+
+            /// Converts the vector to an array of integers, rounding every component towards
+            /// zero, i.e. `-1.5` becomes `-1`. See [`Self::floor_to_i32`] for rounding down.
+            /// Out of range components are saturated and `NaN` becomes `0`.
+            #[inline]
+            pub fn as_i32_array(&self) -> [i32; $dim + 1] {
+                [$(self.$c.to_i32()),*]
+            }
+
+            /// Converts the vector to an array of integers, rounding every component down, i.e.
+            /// `-1.5` becomes `-2`. Useful for finding the grid cell containing a point.
+            #[inline]
+            pub fn floor_to_i32(&self) -> [i32; $dim + 1] {
+                [$(self.$c.floor().to_i32()),*]
+            }
+
+            /// Converts the vector to an array of integers, rounding every component to the
+            /// nearest integer with halves rounded away from zero, i.e. `-1.5` becomes `-2`.
+            #[inline]
+            pub fn round_to_i32(&self) -> [i32; $dim + 1] {
+                [$(self.$c.round().to_i32()),*]
+            }
+
             /// Converts the vector to an array slice.
             #[inline]
             pub fn as_array(&self) -> &[F; $dim + 1] {
@@ -593,9 +937,9 @@ macro_rules! __impl_vec_ops {
             /// Returns angle in radians between two vectors that goes along circle arc
             /// counter-clockwise. Output range is: `[0, 2pi]`.
             /// ```
-            /// # use math::vector;
-            /// let a = vector!(1, 1);
-            /// let b = vector!(-1, 1);
+            /// # use sath::{vector, Vector2d};
+            /// let a: Vector2d = vector!(1, 1);
+            /// let b: Vector2d = vector!(-1, 1);
             /// assert_eq!(a.arc_angle_to(b).to_degrees(), 90.0);
             /// assert_eq!(b.arc_angle_to(a).to_degrees(), 270.0);
             /// /*  (B). -- ~~~ -- .(A)
@@ -626,20 +970,52 @@ macro_rules! __impl_vec_ops {
             }
 
             /// Projects a vector onto another vector. Axis and the resulting vector are collinear.
+            /// If `axis` is zero, the vector becomes zero.
             #[inline]
             pub fn project_onto(&mut self, axis: Self) {
-                let an = axis.normalized();
-
-                *self = an * self.dot(an);
+                *self = self.projected_onto(axis);
             }
 
             /// Returns the projected copy of the vector onto another vector. See
             /// [`Self::project_onto`].
             #[inline]
             pub fn projected_onto(&self, axis: Self) -> Self {
-                let an = axis.normalized();
+                match axis.try_normalized() {
+                    Some(an) => self.projected_onto_unchecked(an),
+                    None => Self::ZERO,
+                }
+            }
+
+            /// Returns the projected copy of the vector onto `unit_axis`, which is assumed to
+            /// be normalized. Skips normalization done by [`Self::projected_onto`].
+            #[inline]
+            pub fn projected_onto_unchecked(&self, unit_axis: Self) -> Self {
+                unit_axis * self.dot(unit_axis)
+            }
 
-                an * self.dot(an)
+            /// Reflects the vector off a surface with `normal`, i.e. `v - 2 * dot(v, n) * n`.
+            /// To avoid unexpected results, use normalized normal.
+            /// `Vector2::reflect` previously reflected across an axis, use
+            /// `Vector2::reflect_across_axis` for that.
+            #[inline]
+            pub fn reflect(&self, normal: Self) -> Self {
+                *self - normal * (self.dot(normal) * F::TWO)
+            }
+
+            /// Reflects the vector off surfaces with every normal in `normals` in sequence.
+            /// See [`Self::reflect`].
+            #[inline]
+            pub fn reflect_all(self, normals: &[Self]) -> Self {
+                normals.iter().fold(self, |v, n| v.reflect(*n))
+            }
+
+            /// Decomposes the vector into parts parallel and perpendicular to `axis`.
+            /// Their sum is equal to the original vector.
+            #[inline]
+            pub fn decompose_along(self, axis: Self) -> (Self, Self) {
+                let parallel = self.projected_onto(axis);
+
+                (parallel, self - parallel)
             }
 
             /// Computes the distance between two vectors.
@@ -654,14 +1030,39 @@ macro_rules! __impl_vec_ops {
                 (other - *self).sqr_magnitude()
             }
 
+            /// Computes the distance to an infinite line passing through `a` and `b`.
+            /// If `a` and `b` are equal, distance to `a` is returned.
+            #[inline]
+            pub fn distance_to_line(&self, a: Self, b: Self) -> F {
+                if (b - a).is_zero() {
+                    return self.distance_to(a);
+                }
+
+                self.distance_to(a.lerp(b, a.inverse_lerp(b, *self)))
+            }
+
+            /// Computes the distance to a segment between `a` and `b`.
+            #[inline]
+            pub fn distance_to_segment(&self, a: Self, b: Self) -> F {
+                if (b - a).is_zero() {
+                    return self.distance_to(a);
+                }
+
+                let t = a.inverse_lerp(b, *self).clamp(F::ZERO, F::ONE);
+                self.distance_to(a.lerp(b, t))
+            }
+
             /// Linearly interpolates between two vectors.
             #[inline]
             pub fn lerp(self, end: Self, t: F) -> Self {
                 self + (end - self) * t
             }
 
-            /// Inverse linear interpolation between two vectors.
+            /// Returns the dot product of `v - self` and `end - self`.
+            /// Despite the name, this is not an inverse of [`Self::lerp`] unless `end - self` is
+            /// a unit vector.
             #[inline]
+            #[deprecated(note = "use `inverse_lerp` instead")]
             pub fn inv_lerp(self, end: Self, v: Self) -> F {
                 let ab = end - self;
                 let av = v - self;
@@ -669,13 +1070,53 @@ macro_rules! __impl_vec_ops {
                 av.dot(ab)
             }
 
+            /// Moves the vector towards `target` using critically damped spring, e.g. for a
+            /// camera following an object. `velocity` is updated in place and should be kept
+            /// between calls, `smooth_time` is roughly the time it takes to reach the target and
+            /// `dt` is the time elapsed since the last call. Never overshoots the target.
+            #[inline]
+            pub fn smooth_damp(
+                self,
+                target: Self,
+                velocity: &mut Self,
+                smooth_time: F,
+                dt: F,
+            ) -> Self {
+                let omega = F::TWO / smooth_time.max(F::EPSILON);
+                let decay = (-omega * dt).exp();
+
+                let change = self - target;
+                let temp = (*velocity + change * omega) * dt;
+                *velocity = (*velocity - temp * omega) * decay;
+
+                let output = target + (change + temp) * decay;
+                if (target - self).dot(output - target) > F::ZERO {
+                    *velocity = Self::ZERO;
+                    return target;
+                }
+
+                output
+            }
+
+            /// Inverse linear interpolation between two vectors. Returns `t` such that
+            /// `self.lerp(end, t)` is the closest point to `value` on the line through `self` and
+            /// `end`.
+            #[inline]
+            pub fn inverse_lerp(self, end: Self, value: Self) -> F {
+                let ab = end - self;
+
+                (value - self).dot(ab) / ab.sqr_magnitude()
+            }
+
             /// Returns normalized copy of the linear interpolation between vectors.
             #[inline]
             pub fn nlerp(self, end: Self, t: F) -> Self {
                 self.lerp(end, t).normalized()
             }
 
-            /// Spherically interpolates between two vectors.
+            /// Spherically interpolates between two vectors. Vectors are expected to be
+            /// normalized, otherwise the result is not guaranteed to lie on the arc between them.
+            /// Results in `NaN` if vectors are parallel.
             #[inline]
             pub fn slerp(self, end: Self, t: F) -> Self {
                 let omega = self.dot_normalized(end).acos();
@@ -684,7 +1125,29 @@ macro_rules! __impl_vec_ops {
                     + end * ((t * omega).sin() / omega.sin())
             }
 
-            /// Computes the product of all elements in the vector.
+            /// Returns the point halfway along the arc between two normalized vectors, same as
+            /// [`Self::slerp`] at `t` = `0.5`, but much cheaper since it is just the normalized
+            /// sum of the vectors. Results in `NaN` if vectors are opposite.
+            #[inline]
+            pub fn spherical_midpoint(self, other: Self) -> Self {
+                (self + other).normalized()
+            }
+
+            /// Returns signed unit axis vector along the component with the largest absolute value.
+            /// E.g. `(-0.2, 0.9, 0.1)` -> `(0, 1, 0)`.
+            /// Ties are resolved in favour of the later component, so a zero vector returns the
+            /// positive last axis, e.g. `+Z` for `Vector3`.
+            #[inline]
+            pub fn dominant_axis(&self) -> Self {
+                let i = self.abs().max_index();
+
+                let mut axis = Self::ZERO;
+                axis[i] = self[i].signum();
+                axis
+            }
+
+            /// Computes the product of all elements in the vector, also known as horizontal
+            /// product.
             #[inline]
             pub fn product(&self) -> F {
                 $(
@@ -692,13 +1155,52 @@ macro_rules! __impl_vec_ops {
                 )* F::ONE
             }
 
-            /// Computes the sum of all elements in the vector.
+            /// Computes the sum of all elements in the vector, also known as horizontal sum.
             #[inline]
             pub fn sum(&self) -> F {
                 $(
                     self.$c +
                 )* F::ZERO
             }
+
+            /// Compares vectors lexicographically using total order of floats, see
+            /// [`f64::total_cmp`]. Unlike `partial_cmp`, it never fails, so it can be used for
+            /// sorting vectors containing `NaN`.
+            #[inline]
+            pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+                core::cmp::Ordering::Equal
+                    $(.then_with(|| self.$c.total_cmp(&other.$c)))*
+            }
+
+            /// Returns maximum element of the vector. Same as `max_element`.
+            #[inline]
+            pub fn horizontal_max(&self) -> F {
+                self.max_element()
+            }
+
+            /// Returns minimum element of the vector. Same as `min_element`.
+            #[inline]
+            pub fn horizontal_min(&self) -> F {
+                self.min_element()
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_swizzles {
+    ($s:ident => $out:ident, $($name:ident: [$($c:ident),*]),* $(,)?) => {
+        impl<F: Float> $s<F> {
+            $(
+                #[doc = concat!(
+                    "Returns a new vector made of `", stringify!($name), "` components."
+                )]
+                #[inline]
+                pub const fn $name(&self) -> $out<F> {
+                    $out::new($(self.$c),*)
+                }
+            )*
         }
     };
 }
@@ -1,5 +1,5 @@
-use crate::{Float, Matrix2, Vector2};
-use std::ops::{Div, DivAssign, Mul, MulAssign};
+use crate::{Angle, Float, Matrix2, Rad, Vector2};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Complex number
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -9,6 +9,12 @@ pub struct Complex<F: Float> {
 }
 
 impl<F: Float> Complex<F> {
+    /// Creates new complex number from real and imaginary parts.
+    #[inline]
+    pub const fn new(real: F, imag: F) -> Self {
+        Self { real, imag }
+    }
+
     /// Converts complex number to a vector where `x` = `real`, `y` = `imag`.
     pub const fn to_vector2(self) -> Vector2<F> {
         Vector2 {
@@ -31,6 +37,20 @@ impl<F: Float> Complex<F> {
         Matrix2::new(self.real, -self.imag, self.imag, self.real)
     }
 
+    /// Converts a real matrix of form `[[a, -b], [b, a]]` to a complex number `a + bi`.
+    /// Returns `None` if the matrix doesn't have such structure with regard to `EPSILON`.
+    #[inline]
+    pub fn from_matrix2(m: Matrix2<F>) -> Option<Self> {
+        if (m.row1.x - m.row2.y).abs() < F::EPSILON && (m.row1.y + m.row2.x).abs() < F::EPSILON {
+            Some(Self {
+                real: m.row1.x,
+                imag: m.row2.x,
+            })
+        } else {
+            None
+        }
+    }
+
     /// Converts `angle` in radians to a complex number.
     #[inline]
     pub fn from_angle(angle: F) -> Self {
@@ -49,7 +69,7 @@ impl<F: Float> Complex<F> {
     /// Computes squared magnitude.
     #[inline]
     pub fn sqr_magnitude(&self) -> F {
-        self.real * self.real + self.imag * self.imag
+        self.real.square() + self.imag.square()
     }
 
     /// Computes magnitude.
@@ -64,6 +84,58 @@ impl<F: Float> Complex<F> {
         (self.magnitude(), self.angle())
     }
 
+    /// Returns complex number's magnitude and typed angle. See [`Self::to_magnitude_angle`].
+    #[inline]
+    pub fn to_polar_typed(self) -> (F, Angle<F, Rad>) {
+        (self.magnitude(), Angle::new(self.angle()))
+    }
+
+    /// Creates a complex number from its magnitude and typed angle.
+    #[inline]
+    pub fn from_polar_typed(magnitude: F, angle: Angle<F, Rad>) -> Self {
+        Self {
+            real: magnitude * angle.cos(),
+            imag: magnitude * angle.sin(),
+        }
+    }
+
+    /// Linearly interpolates between two complex numbers.
+    #[inline]
+    pub fn lerp(self, end: Self, t: F) -> Self {
+        Self {
+            real: self.real + (end.real - self.real) * t,
+            imag: self.imag + (end.imag - self.imag) * t,
+        }
+    }
+
+    /// Interpolates magnitude linearly and angle along the shortest arc between two complex
+    /// numbers. If either of them is zero, angle of the other one is used.
+    pub fn slerp(self, end: Self, t: F) -> Self {
+        let (from_mag, mut from_ang) = self.to_magnitude_angle();
+        let (to_mag, mut to_ang) = end.to_magnitude_angle();
+
+        if from_mag < F::EPSILON {
+            from_ang = to_ang;
+        } else if to_mag < F::EPSILON {
+            to_ang = from_ang;
+        }
+
+        let mut delta = to_ang - from_ang;
+        if delta > F::PI {
+            delta -= F::PI * F::TWO;
+        } else if delta < -F::PI {
+            delta += F::PI * F::TWO;
+        }
+
+        let mag = from_mag + (to_mag - from_mag) * t;
+        let ang = from_ang + delta * t;
+
+        Self {
+            real: mag * ang.cos(),
+            imag: mag * ang.sin(),
+        }
+    }
+
     /// Returns conjugate of the complex number.
     /// Conjugate is defined as `a - bi`.
     #[inline]
@@ -74,7 +146,14 @@ impl<F: Float> Complex<F> {
         }
     }
 
+    /// Checks if both parts of the complex number are zero with regard to `epsilon`.
+    #[inline]
+    pub fn is_zero(&self, epsilon: F) -> bool {
+        self.real.abs() < epsilon && self.imag.abs() < epsilon
+    }
+
     /// Returns `1 / (a + bi)`.
+    /// Results in `inf` or `NaN` parts if the number is zero, see [`Self::try_reciprocal`].
     #[inline]
     pub fn reciprocal(self) -> Self {
         let sqr_mag = self.sqr_magnitude();
@@ -85,12 +164,23 @@ impl<F: Float> Complex<F> {
         }
     }
 
-    /// Computes square root of complex number.
+    /// Returns `1 / (a + bi)` or `None` if the number is zero with regard to `EPSILON`.
+    #[inline]
+    pub fn try_reciprocal(self) -> Option<Self> {
+        if self.is_zero(F::EPSILON) {
+            None
+        } else {
+            Some(self.reciprocal())
+        }
+    }
+
+    /// Computes square roots of complex number. The first one is the principal root, whose
+    /// imaginary part has the same sign as `imag`, including signed zero.
     #[inline]
     pub fn sqrt(self) -> (Self, Self) {
         let mag = self.magnitude();
         let gamma = ((self.real + mag) / F::TWO).sqrt();
-        let delta = self.imag.signum() * ((-self.real + mag) / F::TWO).sqrt();
+        let delta = ((-self.real + mag) / F::TWO).sqrt().copysign(self.imag);
 
         (
             Self {
@@ -112,6 +202,75 @@ impl<F: Float> Complex<F> {
             imag: self.real.exp() * self.imag.sin(),
         }
     }
+
+    /// Computes the principal value of the natural logarithm, with imaginary part in
+    /// `(-pi, pi]` range.
+    #[inline]
+    pub fn ln(&self) -> Self {
+        Self {
+            real: self.magnitude().ln(),
+            imag: self.angle(),
+        }
+    }
+
+    /// Raises complex number to a complex power. Uses the principal branch of the logarithm,
+    /// see [`Self::ln`].
+    #[inline]
+    pub fn powc(self, exponent: Self) -> Self {
+        (exponent * self.ln()).exp()
+    }
+}
+
+impl<F: Float> Add for Complex<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real + rhs.real,
+            imag: self.imag + rhs.imag,
+        }
+    }
+}
+
+impl<F: Float> AddAssign for Complex<F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.real += rhs.real;
+        self.imag += rhs.imag;
+    }
+}
+
+impl<F: Float> Sub for Complex<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real - rhs.real,
+            imag: self.imag - rhs.imag,
+        }
+    }
+}
+
+impl<F: Float> SubAssign for Complex<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.real -= rhs.real;
+        self.imag -= rhs.imag;
+    }
+}
+
+impl<F: Float> Neg for Complex<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            real: -self.real,
+            imag: -self.imag,
+        }
+    }
 }
 
 impl<F: Float> Mul for Complex<F> {
@@ -138,6 +297,8 @@ impl<F: Float> MulAssign for Complex<F> {
 impl<F: Float> Div for Complex<F> {
     type Output = Self;
 
+    /// Divides by multiplying with [`Complex::reciprocal`], so dividing by zero results in
+    /// `inf` or `NaN` parts. Use [`Complex::try_reciprocal`] for a checked division.
     #[inline]
     fn div(self, rhs: Self) -> Self::Output {
         self * rhs.reciprocal()
@@ -162,6 +323,31 @@ impl<F: Float> From<Vector2<F>> for Complex<F> {
     }
 }
 
+/// Solves quadratic equation `ax² + bx + c = 0`, returning both of its roots. `a` must be
+/// non-zero. Roots are computed in a numerically stable way, the one with larger magnitude is
+/// found first and the other one is derived from it to avoid cancellation.
+pub fn solve_quadratic<F: Float>(
+    a: Complex<F>,
+    b: Complex<F>,
+    c: Complex<F>,
+) -> (Complex<F>, Complex<F>) {
+    let four = Complex::new(F::TWO * F::TWO, F::ZERO);
+    let (root, neg_root) = (b * b - four * a * c).sqrt();
+
+    let q = if (b + root).sqr_magnitude() >= (b + neg_root).sqr_magnitude() {
+        b + root
+    } else {
+        b + neg_root
+    };
+    let q = q / Complex::new(-F::TWO, F::ZERO);
+
+    if q.sqr_magnitude() == F::ZERO {
+        return (q, q);
+    }
+
+    (q / a, c / q)
+}
+
 /// Creates new complex number where `real` = first argument and `imag` = second argument.
 #[macro_export]
 macro_rules! complex {
@@ -1,6 +1,6 @@
-use crate::{Deg, Float, Measure, Rad};
+use crate::{Deg, Float, Matrix3, Measure, Quaternion, Rad, Vector3};
 use std::{
-    fmt::{self, Debug},
+    fmt::{self, Debug, Display},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
@@ -28,6 +28,26 @@ pub struct Euler<A: Measure, F: Float> {
     _pd: PhantomData<A>,
 }
 
+/// Order in which euler rotations are applied, all of them are around fixed world axes.
+/// For example, [`EulerOrder::XYZ`] first rotates around `X` axis by pitch, then around `Y`
+/// axis by roll and then around `Z` axis by yaw.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    /// Pitch, roll, yaw. Used by [`Quaternion::from_euler`].
+    #[default]
+    XYZ,
+    /// Pitch, yaw, roll.
+    XZY,
+    /// Roll, pitch, yaw.
+    YXZ,
+    /// Roll, yaw, pitch.
+    YZX,
+    /// Yaw, pitch, roll.
+    ZXY,
+    /// Yaw, roll, pitch.
+    ZYX,
+}
+
 impl<A: Measure, F: Float> Debug for Euler<A, F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Euler")
@@ -38,6 +58,19 @@ impl<A: Measure, F: Float> Debug for Euler<A, F> {
     }
 }
 
+impl<A: Measure, F: Float> Display for Euler<A, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Euler {{ yaw: {}{suffix}, pitch: {}{suffix}, roll: {}{suffix} }}",
+            self.yaw,
+            self.pitch,
+            self.roll,
+            suffix = A::SUFFIX
+        )
+    }
+}
+
 impl<A: Measure, F: Float> Euler<A, F> {
     /// Creates new euler angles from `yaw`, `pitch`, `roll`.
     pub fn new(yaw: F, pitch: F, roll: F) -> Self {
@@ -51,6 +84,27 @@ impl<A: Measure, F: Float> Euler<A, F> {
 }
 
 impl<F: Float> Euler<Rad, F> {
+    /// Creates euler angles that rotate `Y` axis to face `forward` with `Z` axis aligned to
+    /// `up`. If `forward` is parallel to `up`, `X` axis is kept as close to its original
+    /// direction as possible.
+    pub fn from_look_direction(forward: Vector3<F>, up: Vector3<F>) -> Self {
+        let forward = forward.normalized();
+
+        let mut right = forward.cross(up);
+        if right.is_zero() {
+            right = if forward.x.abs() < F::ONE / F::TWO {
+                Vector3::X
+            } else {
+                Vector3::Y
+            };
+            right -= forward * forward.dot(right);
+        }
+        let right = right.normalized();
+        let up = right.cross(forward);
+
+        Quaternion::from_matrix3(Matrix3::from_columns(right, forward, up)).into_euler()
+    }
+
     /// Converts radians to degrees.
     pub fn to_degrees(self) -> Euler<Deg, F> {
         Euler {
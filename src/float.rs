@@ -37,6 +37,29 @@ macro_rules! forward_float_impl {
             const TWO: Self;
             const ONE: Self;
             const ZERO: Self;
+            /// Smallest finite value.
+            const MIN: Self;
+            /// Largest finite value.
+            const MAX: Self;
+            /// Smallest positive normal value.
+            const MIN_POSITIVE: Self;
+
+            /// Returns `self * self`.
+            #[inline(always)]
+            fn square(&self) -> Self {
+                *self * *self
+            }
+
+            /// Converts to `i32` rounding towards zero. Values out of range are saturated and
+            /// `NaN` is converted to `0`.
+            fn to_i32(&self) -> i32;
+
+            /// Returns raw bit representation, `f32` is zero-extended to `u64`.
+            fn to_bits(&self) -> u64;
+
+            /// Creates from raw bit representation, `f32` uses lower 32 bits. See
+            /// [`Float::to_bits`].
+            fn from_bits(bits: u64) -> Self;
 
             $(
                 fn $method(&self, $($aname: $aty),*) $(-> $ret)?;
@@ -49,6 +72,24 @@ macro_rules! forward_float_impl {
             const TWO: Self = 2.0;
             const ONE: Self = 1.0;
             const ZERO: Self = 0.0;
+            const MIN: Self = f32::MIN;
+            const MAX: Self = f32::MAX;
+            const MIN_POSITIVE: Self = f32::MIN_POSITIVE;
+
+            #[inline(always)]
+            fn to_i32(&self) -> i32 {
+                *self as i32
+            }
+
+            #[inline(always)]
+            fn to_bits(&self) -> u64 {
+                f32::to_bits(*self) as u64
+            }
+
+            #[inline(always)]
+            fn from_bits(bits: u64) -> Self {
+                f32::from_bits(bits as u32)
+            }
 
             $(
                 #[inline(always)]
@@ -64,6 +105,24 @@ macro_rules! forward_float_impl {
             const TWO: Self = 2.0;
             const ONE: Self = 1.0;
             const ZERO: Self = 0.0;
+            const MIN: Self = f64::MIN;
+            const MAX: Self = f64::MAX;
+            const MIN_POSITIVE: Self = f64::MIN_POSITIVE;
+
+            #[inline(always)]
+            fn to_i32(&self) -> i32 {
+                *self as i32
+            }
+
+            #[inline(always)]
+            fn to_bits(&self) -> u64 {
+                f64::to_bits(*self)
+            }
+
+            #[inline(always)]
+            fn from_bits(bits: u64) -> Self {
+                f64::from_bits(bits)
+            }
 
             $(
                 #[inline(always)]
@@ -91,4 +150,12 @@ forward_float_impl! { f32, f64,
     fn clamp(from: Self, to: Self) -> Self;
     fn max(other: Self) -> Self;
     fn min(other: Self) -> Self;
+    fn powi(n: i32) -> Self;
+    fn powf(n: Self) -> Self;
+    fn copysign(sign: Self) -> Self;
+    fn is_sign_negative() -> bool;
+    fn is_finite() -> bool;
+    fn total_cmp(other: &Self) -> std::cmp::Ordering;
+    fn floor() -> Self;
+    fn round() -> Self;
 }
@@ -0,0 +1,55 @@
+use crate::Float;
+use std::ops::{Add, Mul, Sub};
+
+/// Types supporting GLSL-like [`step`] and [`smoothstep`] functions. For vectors they are
+/// applied component-wise.
+pub trait Step: Sized {
+    /// Returns `0` if `self` is less than `edge` and `1` otherwise.
+    fn step(self, edge: Self) -> Self;
+
+    /// Performs smooth Hermite interpolation between `0` and `1` when `self` is between `edge0`
+    /// and `edge1`. Result is undefined if `edge0` >= `edge1`.
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self;
+}
+
+impl<F: Float> Step for F {
+    #[inline]
+    fn step(self, edge: Self) -> Self {
+        if self < edge {
+            F::ZERO
+        } else {
+            F::ONE
+        }
+    }
+
+    #[inline]
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = ((self - edge0) / (edge1 - edge0)).clamp(F::ZERO, F::ONE);
+
+        t * t * (F::TWO + F::ONE - F::TWO * t)
+    }
+}
+
+/// Linearly interpolates between `a` and `b`. Same as GLSL `mix`.
+#[inline]
+pub fn mix<F, V>(a: V, b: V, t: F) -> V
+where
+    F: Float,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<F, Output = V>,
+{
+    a + (b - a) * t
+}
+
+/// Returns `0` if `x` is less than `edge` and `1` otherwise. Same as GLSL `step`.
+/// See [`Step::step`].
+#[inline]
+pub fn step<V: Step>(edge: V, x: V) -> V {
+    x.step(edge)
+}
+
+/// Performs smooth Hermite interpolation between `0` and `1` when `x` is between `edge0` and
+/// `edge1`. Same as GLSL `smoothstep`. See [`Step::smoothstep`].
+#[inline]
+pub fn smoothstep<V: Step>(edge0: V, edge1: V, x: V) -> V {
+    x.smoothstep(edge0, edge1)
+}
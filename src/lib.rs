@@ -19,3 +19,7 @@ mod float;
 pub use float::*;
 mod aabb;
 pub use aabb::*;
+mod plane;
+pub use plane::*;
+mod glsl;
+pub use glsl::*;
@@ -14,15 +14,6 @@ pub struct Matrix2<F: Float> {
     pub row2: Vector2<F>,
 }
 
-impl<F: Float> Matrix2<F> {
-    /// Matrix with all elements equal to `0`.
-    pub const ZERO: Self = Self::new(F::ZERO, F::ZERO, F::ZERO, F::ZERO);
-    /// Matrix with all elements equal to `1`.
-    pub const ONE: Self = Self::new(F::ONE, F::ONE, F::ONE, F::ONE);
-    /// Identity matrix with diagonal elements equal to `1` and `0` for every other.
-    pub const IDENTITY: Self = Self::new(F::ONE, F::ZERO, F::ZERO, F::ONE);
-}
-
 impl<F: Float> Matrix2<F> {
     /// Creates a new matrix from individual elements.
     pub const fn new(m11: F, m12: F, m21: F, m22: F) -> Self {
@@ -127,12 +118,19 @@ impl<F: Float> Matrix2<F> {
     }
 
     /// Creates a matrix that represents a 2d rotation by `angle` around origin counter-clockwise.
+    /// Same as converting [`Complex::from_angle`] to a matrix.
     #[inline]
     pub fn from_angle(angle: F) -> Self {
-        Self {
-            row1: Vector2::new(angle.cos(), -angle.sin()),
-            row2: Vector2::new(angle.sin(), angle.cos()),
-        }
+        Complex::from_angle(angle).to_matrix2()
+    }
+
+    /// Creates a matrix that reflects vectors across a line with normal `axis_normal` going
+    /// through origin.
+    /// To avoid unexpected results, use normalized normal.
+    pub fn new_reflection(axis_normal: Vector2<F>) -> Self {
+        let n = axis_normal * F::TWO;
+
+        Self::IDENTITY - Self::from_rows(n * axis_normal.x, n * axis_normal.y)
     }
 
     /// Converts a complex number to a matrix.
@@ -181,6 +179,8 @@ impl<F: Float> Mul for Matrix2<F> {
 
 impl<F: Float> MulAssign for Matrix2<F> {
     fn mul_assign(&mut self, rhs: Self) {
+        // Every new row only depends on the same row of `self` and on `rhs`, which is a copy,
+        // so rows can be overwritten in place one by one.
         self.row1 = Vector2 {
             x: self.row1.dot(rhs.column(1)),
             y: self.row1.dot(rhs.column(2)),
@@ -216,4 +216,4 @@ unsafe impl<F: Float> bytemuck::Pod for Matrix2<F> {}
 #[cfg(feature = "bytemuck")]
 unsafe impl<F: Float> bytemuck::Zeroable for Matrix2<F> {}
 
-crate::__impl_mat_ops!(Matrix2, Vector2, 2, row1, row2);
+crate::__impl_mat_ops!(Matrix2, Vector2, 2, row1: X, row2: Y);
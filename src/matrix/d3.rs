@@ -1,4 +1,4 @@
-use crate::{vector, Float, Matrix4, Quaternion, Vector3};
+use crate::{matrix, vector, Float, Matrix4, Quaternion, Vector2, Vector3};
 use std::{
     fmt,
     mem::swap,
@@ -17,29 +17,6 @@ pub struct Matrix3<F: Float> {
     pub row3: Vector3<F>,
 }
 
-impl<F: Float> Matrix3<F> {
-    /// Matrix with all elements equal to `0`.
-    pub const ZERO: Self = Self {
-        row1: Vector3::ZERO,
-        row2: Vector3::ZERO,
-        row3: Vector3::ZERO,
-    };
-
-    /// Matrix with all elements equal to `1`.
-    pub const ONE: Self = Self {
-        row1: Vector3::ONE,
-        row2: Vector3::ONE,
-        row3: Vector3::ONE,
-    };
-
-    /// Identity matrix with diagonal elements equal to `1` and `0` for every other.
-    pub const IDENTITY: Self = Self {
-        row1: Vector3::new(F::ONE, F::ZERO, F::ZERO),
-        row2: Vector3::new(F::ZERO, F::ONE, F::ZERO),
-        row3: Vector3::new(F::ZERO, F::ZERO, F::ONE),
-    };
-}
-
 impl<F: Float> Matrix3<F> {
     /// Creates a new matrix from individual elements.
     #[allow(clippy::too_many_arguments)]
@@ -76,6 +53,16 @@ impl<F: Float> Matrix3<F> {
         }
     }
 
+    /// Creates a symmetric matrix from its diagonal and off-diagonal elements, where
+    /// `off` = `(m12, m13, m23)`.
+    pub const fn from_symmetric(diag: Vector3<F>, off: Vector3<F>) -> Self {
+        Self {
+            row1: Vector3::new(diag.x, off.x, off.y),
+            row2: Vector3::new(off.x, diag.y, off.z),
+            row3: Vector3::new(off.y, off.z, diag.z),
+        }
+    }
+
     /// Creates a matrix from individual rows.
     pub const fn from_rows(row1: Vector3<F>, row2: Vector3<F>, row3: Vector3<F>) -> Self {
         Self { row1, row2, row3 }
@@ -90,6 +77,24 @@ impl<F: Float> Matrix3<F> {
         }
     }
 
+    /// Creates a row permutation matrix. Multiplying it by a vector `v` produces a vector
+    /// whose `i`th component is `v[perm[i]]`.
+    /// # Panics
+    /// If `perm` is not a permutation of `[0, 1, 2]`.
+    pub fn permutation(perm: [usize; 3]) -> Self {
+        let mut seen = [false; 3];
+        for &p in &perm {
+            assert!(p < 3 && !seen[p], "Invalid permutation: {perm:?}");
+            seen[p] = true;
+        }
+
+        let mut m = Self::ZERO;
+        for (i, &p) in perm.iter().enumerate() {
+            m[i][p] = F::ONE;
+        }
+        m
+    }
+
     /// Creates a matrix which specifies a rotation around `X` axis.
     pub fn new_rotation_x(angle: F) -> Self {
         Self {
@@ -145,14 +150,53 @@ impl<F: Float> Matrix3<F> {
         Self::new_diagonal(scale)
     }
 
+    /// Creates a 2D homogeneous matrix that scales by `scale` about `pivot`, i.e. translates
+    /// `pivot` to the origin, scales and translates back. `pivot` is not moved by the transform.
+    pub fn new_scale_about(scale: Vector2<F>, pivot: Vector2<F>) -> Self {
+        Self {
+            row1: Vector3::new(scale.x, F::ZERO, pivot.x * (F::ONE - scale.x)),
+            row2: Vector3::new(F::ZERO, scale.y, pivot.y * (F::ONE - scale.y)),
+            row3: Vector3::Z,
+        }
+    }
+
+    /// Creates a matrix that reflects vectors across a plane with `normal` going through origin.
+    /// To avoid unexpected results, use normalized normal.
+    pub fn new_reflection(normal: Vector3<F>) -> Self {
+        let n = normal * F::TWO;
+
+        Self::IDENTITY - Self::from_rows(n * normal.x, n * normal.y, n * normal.z)
+    }
+
     /// Extracts an axis of rotation if matrix represents a rotation.
     pub fn rotation_axis(&self) -> Vector3<F> {
-        vector!(
+        let antisym = vector!(
             self.row3.y - self.row2.z,
             self.row1.z - self.row3.x,
             self.row2.x - self.row1.y,
-        )
-        .normalized()
+        );
+
+        let cos = (self.trace() - F::ONE) / F::TWO;
+        if cos >= F::ZERO {
+            return antisym.normalized();
+        }
+
+        // Antisymmetric part vanishes as the angle approaches `pi`, so the axis is recovered from
+        // the symmetric part instead: `(M + Mt) / 2 = cos * I + (1 - cos) * axis * axis^T`.
+        let outer_diag = (self.diagonal() - Vector3::same(cos)) / (F::ONE - cos);
+        let i = outer_diag.max_index();
+
+        let mut axis = Vector3::ZERO;
+        axis[i] = outer_diag[i].max(F::ZERO).sqrt();
+        for j in (0..3).filter(|&j| j != i) {
+            axis[j] = (self[i][j] + self[j][i]) / (F::TWO * (F::ONE - cos) * axis[i]);
+        }
+
+        if axis.dot(antisym) < F::ZERO {
+            axis = -axis;
+        }
+
+        axis.normalized()
     }
 
     /// Extracts an angle of rotation if matrix represents a rotation.
@@ -188,6 +232,59 @@ impl<F: Float> Matrix3<F> {
         }
     }
 
+    /// Creates a rotation matrix from rotation vector `omega`, whose direction is the axis and
+    /// magnitude is the angle of rotation in radians, using Rodrigues' formula.
+    /// This is the exponential map from `so(3)` to `SO(3)`. See [`Self::log_so3`].
+    pub fn exp_so3(omega: Vector3<F>) -> Self {
+        let sqr_angle = omega.sqr_magnitude();
+        #[rustfmt::skip]
+        let skew: Self = matrix!(
+            F::ZERO, -omega.z, omega.y,
+            omega.z, F::ZERO, -omega.x,
+            -omega.y, omega.x, F::ZERO,
+        );
+
+        // Taylor series of `sin(x) / x` and `(1 - cos(x)) / x^2` for small angles.
+        let (a, b) = if sqr_angle < F::EPSILON.sqrt() {
+            let six = F::TWO * (F::TWO + F::ONE);
+
+            (
+                F::ONE - sqr_angle / six,
+                (F::ONE - sqr_angle / (six * F::TWO)) / F::TWO,
+            )
+        } else {
+            let angle = sqr_angle.sqrt();
+
+            (angle.sin() / angle, (F::ONE - angle.cos()) / sqr_angle)
+        };
+
+        Self::IDENTITY + skew * a + skew * skew * b
+    }
+
+    /// Converts a rotation matrix to a rotation vector, whose direction is the axis and
+    /// magnitude is the angle of rotation in `[0, pi]` range.
+    /// This is the logarithmic map from `SO(3)` to `so(3)`. See [`Self::exp_so3`].
+    pub fn log_so3(&self) -> Vector3<F> {
+        let antisym = Vector3::new(
+            self.row3.y - self.row2.z,
+            self.row1.z - self.row3.x,
+            self.row2.x - self.row1.y,
+        );
+
+        let cos = (self.trace() - F::ONE) / F::TWO;
+        let angle = (antisym.magnitude() / F::TWO).atan2(cos);
+
+        if cos < F::ZERO {
+            // Antisymmetric part vanishes near `pi`, see `rotation_axis`.
+            self.rotation_axis() * angle
+        } else if angle.square() < F::EPSILON.sqrt() {
+            // Taylor series of `x / (2 * sin(x))` for small angles.
+            antisym * ((F::ONE + angle.square() / (F::TWO * (F::TWO + F::ONE))) / F::TWO)
+        } else {
+            antisym * (angle / (F::TWO * angle.sin()))
+        }
+    }
+
     /// Sum of diagonal elements.
     #[inline]
     pub fn trace(&self) -> F {
@@ -289,9 +386,39 @@ impl<F: Float> Matrix3<F> {
     /// Computes the determinant of the matrix.
     pub fn det(&self) -> F {
         let mut copy = *self;
-        copy.to_row_echelon();
+        let swaps = copy.to_row_echelon();
+
+        if swaps.is_multiple_of(2) {
+            copy.diagonal().product()
+        } else {
+            -copy.diagonal().product()
+        }
+    }
+
+    /// Expresses the matrix in a new basis by computing `basisᵀ * self * basis`, e.g. to rotate
+    /// an inertia tensor. Equal to a similarity transform when `basis` is orthogonal.
+    pub fn similarity_transform(&self, basis: &Self) -> Self {
+        basis.transposed() * *self * *basis
+    }
+
+    /// Returns a copy of the matrix with orthonormalized columns using Gram-Schmidt process.
+    /// Can be used to strip scale from a rotation matrix.
+    pub fn orthonormalized(&self) -> Self {
+        let c1 = self.column(1).normalized();
+
+        let c2 = self.column(2);
+        let c2 = (c2 - c1 * c1.dot(c2)).normalized();
+
+        let c3 = self.column(3);
+        let c3 = (c3 - c1 * c1.dot(c3) - c2 * c2.dot(c3)).normalized();
+
+        Self::from_columns(c1, c2, c3)
+    }
 
-        copy.diagonal().product()
+    /// Checks if columns of the matrix form a right-handed basis, i.e. the determinant is
+    /// positive.
+    pub fn is_right_handed(&self) -> bool {
+        self.row1.triple(self.row2, self.row3) > F::ZERO
     }
 
     /// Converts from a quaternion to a matrix.
@@ -384,4 +511,4 @@ unsafe impl<F: Float> bytemuck::Pod for Matrix3<F> {}
 #[cfg(feature = "bytemuck")]
 unsafe impl<F: Float> bytemuck::Zeroable for Matrix3<F> {}
 
-crate::__impl_mat_ops!(Matrix3, Vector3, 3, row1, row2, row3);
+crate::__impl_mat_ops!(Matrix3, Vector3, 3, row1: X, row2: Y, row3: Z);
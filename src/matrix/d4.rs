@@ -1,10 +1,42 @@
-use crate::{Float, Vector3, Vector4};
+use crate::{Angle, Float, Matrix3, Measure, Quaternion, Vector3, Vector4};
 use std::{
     fmt,
     mem::swap,
     ops::{Mul, MulAssign},
 };
 
+/// Range of depth values produced by a projection matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthRange {
+    /// Near plane is mapped to `-1`, far plane to `1`. Used by OpenGL.
+    NegativeOneToOne,
+    /// Near plane is mapped to `0`, far plane to `1`. Used by Direct3D, Vulkan, Metal.
+    ZeroToOne,
+    /// Near plane is mapped to `1`, far plane to `0`. Also known as reversed Z.
+    OneToZero,
+}
+
+impl DepthRange {
+    /// Returns depth values of near and far planes.
+    pub fn near_far<F: Float>(self) -> (F, F) {
+        match self {
+            Self::NegativeOneToOne => (-F::ONE, F::ONE),
+            Self::ZeroToOne => (F::ZERO, F::ONE),
+            Self::OneToZero => (F::ONE, F::ZERO),
+        }
+    }
+}
+
+/// Handedness of a coordinate system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Handedness {
+    /// Camera looks down the `-Z` axis. Used by OpenGL.
+    #[default]
+    Right,
+    /// Camera looks down the `+Z` axis. Used by Direct3D.
+    Left,
+}
+
 /// Row major 4x4 matrix.
 #[derive(Clone, Copy, PartialEq)]
 pub struct Matrix4<F: Float> {
@@ -14,29 +46,6 @@ pub struct Matrix4<F: Float> {
     pub row4: Vector4<F>,
 }
 
-impl<F: Float> Matrix4<F> {
-    pub const ZERO: Self = Self {
-        row1: Vector4::ZERO,
-        row2: Vector4::ZERO,
-        row3: Vector4::ZERO,
-        row4: Vector4::ZERO,
-    };
-
-    pub const ONE: Self = Self {
-        row1: Vector4::ONE,
-        row2: Vector4::ONE,
-        row3: Vector4::ONE,
-        row4: Vector4::ONE,
-    };
-
-    pub const IDENTITY: Self = Self {
-        row1: Vector4::X,
-        row2: Vector4::Y,
-        row3: Vector4::Z,
-        row4: Vector4::W,
-    };
-}
-
 impl<F: Float> Matrix4<F> {
     #[rustfmt::skip]
     #[allow(clippy::too_many_arguments)]
@@ -73,6 +82,174 @@ impl<F: Float> Matrix4<F> {
         m
     }
 
+    /// Creates new matrix that scales by `scale` about `pivot`, i.e. translates `pivot` to the
+    /// origin, scales and translates back. `pivot` is not moved by the transform.
+    pub fn new_scale_about(scale: Vector3<F>, pivot: Vector3<F>) -> Self {
+        Self {
+            row1: Vector4::new(scale.x, F::ZERO, F::ZERO, pivot.x * (F::ONE - scale.x)),
+            row2: Vector4::new(F::ZERO, scale.y, F::ZERO, pivot.y * (F::ONE - scale.y)),
+            row3: Vector4::new(F::ZERO, F::ZERO, scale.z, pivot.z * (F::ONE - scale.z)),
+            row4: Vector4::W,
+        }
+    }
+
+    /// Truncates matrix to [`Matrix3`], removing the last row and column.
+    pub const fn truncate(&self) -> Matrix3<F> {
+        Matrix3 {
+            row1: self.row1.truncate(),
+            row2: self.row2.truncate(),
+            row3: self.row3.truncate(),
+        }
+    }
+
+    /// Creates a right-handed perspective projection matrix looking down the `-Z` axis, that maps
+    /// depth to `[-1, 1]` range.
+    /// `fov_y` is a vertical field of view in radians, `aspect` is width divided by height.
+    /// # Panics
+    /// See [`Self::new_perspective_full`].
+    pub fn new_perspective_projection(fov_y: F, aspect: F, near: F, far: F) -> Self {
+        Self::new_perspective_full(fov_y, aspect, near, Some(far), DepthRange::NegativeOneToOne)
+    }
+
+    /// Creates a right-handed perspective projection matrix looking down the `-Z` axis.
+    /// If `far` is `None`, far plane is placed at infinity. `depth_range` specifies to which
+    /// depth values near and far planes are mapped.
+    /// # Panics
+    /// If `aspect` is not a positive finite number, e.g. if height was zero, if `fov_y` is not in
+    /// `(0, pi)` range, if `near` is not positive or if `far` is not greater than `near`.
+    pub fn new_perspective_full(
+        fov_y: F,
+        aspect: F,
+        near: F,
+        far: Option<F>,
+        depth_range: DepthRange,
+    ) -> Self {
+        assert!(
+            aspect > F::ZERO && aspect.is_finite(),
+            "Aspect ratio must be positive and finite, got: {aspect}"
+        );
+        assert!(
+            fov_y > F::ZERO && fov_y < F::PI,
+            "Vertical field of view must be in (0, pi) range, got: {fov_y}"
+        );
+        assert!(near > F::ZERO, "Near plane must be positive, got: {near}");
+        if let Some(far) = far {
+            assert!(
+                far > near,
+                "Far plane must be further than near plane, got: {far}"
+            );
+        }
+
+        let half = fov_y / F::TWO;
+        let f = half.cos() / half.sin();
+
+        let (near_depth, far_depth): (F, F) = depth_range.near_far();
+        let a = match far {
+            Some(far) => (far_depth * far - near_depth * near) / (near - far),
+            None => -far_depth,
+        };
+        let b = near * (near_depth + a);
+
+        Self {
+            row1: Vector4::new(f / aspect, F::ZERO, F::ZERO, F::ZERO),
+            row2: Vector4::new(F::ZERO, f, F::ZERO, F::ZERO),
+            row3: Vector4::new(F::ZERO, F::ZERO, a, b),
+            row4: Vector4::new(F::ZERO, F::ZERO, -F::ONE, F::ZERO),
+        }
+    }
+
+    /// Same as [`Self::new_perspective_projection`] but takes a typed vertical field of view,
+    /// converting it to radians.
+    pub fn new_perspective_projection_angle<M: Measure>(
+        fov_y: Angle<F, M>,
+        aspect: F,
+        near: F,
+        far: F,
+    ) -> Self {
+        Self::new_perspective_projection(fov_y.into_radians().0, aspect, near, far)
+    }
+
+    /// Creates a perspective projection matrix from parameters of its frustum.
+    /// Same as [`Self::new_perspective_projection`].
+    pub fn from_fov_planes(fov_y: F, aspect: F, near: F, far: F) -> Self {
+        Self::new_perspective_projection(fov_y, aspect, near, far)
+    }
+
+    /// Extracts frustum planes from a projection (or view-projection) matrix, whose depth is
+    /// mapped to `depth_range`.
+    /// Planes are stored as `(a, b, c, d)` for `ax + by + cz + d = 0` with normals pointing
+    /// inside of the frustum and are not normalized.
+    /// Order is: left, right, bottom, top, near, far.
+    pub fn frustum_planes(&self, depth_range: DepthRange) -> [Vector4<F>; 6] {
+        let (near, far) = match depth_range {
+            DepthRange::NegativeOneToOne => (self.row4 + self.row3, self.row4 - self.row3),
+            DepthRange::ZeroToOne => (self.row3, self.row4 - self.row3),
+            DepthRange::OneToZero => (self.row4 - self.row3, self.row3),
+        };
+
+        [
+            self.row4 + self.row1,
+            self.row4 - self.row1,
+            self.row4 + self.row2,
+            self.row4 - self.row2,
+            near,
+            far,
+        ]
+    }
+
+    /// Creates a right-handed view matrix for a camera at `eye` looking at `target`.
+    /// See [`Self::new_look_at_handed`].
+    pub fn new_look_at(eye: Vector3<F>, target: Vector3<F>, up: Vector3<F>) -> Self {
+        Self::new_look_at_handed(eye, target, up, Handedness::Right)
+    }
+
+    /// Creates a view matrix for a camera at `eye` looking at `target`, aligned with `up`.
+    /// The resulting matrix transforms `eye` to the origin and the view direction to `-Z` axis
+    /// for [`Handedness::Right`] or to `+Z` axis for [`Handedness::Left`].
+    pub fn new_look_at_handed(
+        eye: Vector3<F>,
+        target: Vector3<F>,
+        up: Vector3<F>,
+        handedness: Handedness,
+    ) -> Self {
+        let forward = (target - eye).normalized();
+
+        let (x, y, z) = match handedness {
+            Handedness::Right => {
+                let side = forward.cross(up).normalized();
+                (side, side.cross(forward), -forward)
+            }
+            Handedness::Left => {
+                let side = up.cross(forward).normalized();
+                (side, forward.cross(side), forward)
+            }
+        };
+
+        Self {
+            row1: x.extend(-x.dot(eye)),
+            row2: y.extend(-y.dot(eye)),
+            row3: z.extend(-z.dot(eye)),
+            row4: Vector4::W,
+        }
+    }
+
+    /// Creates a model transform that places an object at `position` and orients it so that
+    /// its `-Z` axis points towards `target` and its `Y` axis is aligned with `up`.
+    /// Unlike [`Self::new_look_at`] which creates a view matrix for a camera, this matrix is
+    /// not inverted and can be used to place billboards, turrets and so on.
+    pub fn new_model_look_at(position: Vector3<F>, target: Vector3<F>, up: Vector3<F>) -> Self {
+        let forward = (target - position).normalized();
+        let side = forward.cross(up).normalized();
+        let up = side.cross(forward);
+
+        Self::from_columns(
+            side.extend(F::ZERO),
+            up.extend(F::ZERO),
+            (-forward).extend(F::ZERO),
+            position.extend(F::ONE),
+        )
+    }
+
     /// Creates a matrix from individual rows.
     pub const fn from_rows(
         row1: Vector4<F>,
@@ -213,9 +390,156 @@ impl<F: Float> Matrix4<F> {
     /// Computes the determinant of the matrix.
     pub fn det(&self) -> F {
         let mut copy = *self;
-        copy.to_row_echelon();
+        let swaps = copy.to_row_echelon();
+
+        if swaps.is_multiple_of(2) {
+            copy.diagonal().product()
+        } else {
+            -copy.diagonal().product()
+        }
+    }
+
+    /// Returns a copy of the matrix with translation column zeroed out. Transforming a point
+    /// with the result is the same as transforming a direction with the original matrix.
+    pub fn without_translation(&self) -> Self {
+        let mut m = *self;
+        m.row1.w = F::ZERO;
+        m.row2.w = F::ZERO;
+        m.row3.w = F::ZERO;
+        m
+    }
+
+    /// Extracts rotational part of the matrix by orthonormalizing its upper-left 3x3 part.
+    /// If the matrix mirrors, i.e. isn't right-handed, the first axis is flipped so the result
+    /// is always a proper rotation, same as in [`Self::decompose_trs`].
+    /// See [`Matrix3::orthonormalized`].
+    pub fn rotation_only(&self) -> Matrix3<F> {
+        let m = self.truncate().orthonormalized();
+        if m.is_right_handed() {
+            m
+        } else {
+            Matrix3::from_columns(-m.column(1), m.column(2), m.column(3))
+        }
+    }
+
+    /// Checks if the upper-left 3x3 part of the matrix forms a right-handed basis.
+    /// See [`Matrix3::is_right_handed`].
+    pub fn is_right_handed(&self) -> bool {
+        self.truncate().is_right_handed()
+    }
+
+    /// Creates a transform matrix equal to `T * R * S`, i.e. the one that scales, then rotates
+    /// and then translates. See [`Self::decompose_trs`].
+    pub fn from_trs(translation: Vector3<F>, rotation: Quaternion<F>, scale: Vector3<F>) -> Self {
+        (rotation.into_matrix3() * Matrix3::new_scale(scale)).extend(
+            Vector3::ZERO,
+            translation,
+            F::ONE,
+        )
+    }
+
+    /// Blends two transform matrices by decomposing them, interpolating translation and scale
+    /// linearly, rotation spherically along the shortest path, and recomposing the result.
+    /// Unlike element-wise `lerp`, it doesn't shrink or shear intermediate rotations, e.g.
+    /// element-wise halfway between rotations by `0` and `pi` around some axis is a degenerate
+    /// matrix.
+    /// Returns `None` if any of the matrices can't be decomposed, see [`Self::decompose_trs`].
+    pub fn lerp_trs(&self, other: &Self, t: F) -> Option<Self> {
+        let (from_t, from_r, from_s) = self.decompose_trs()?;
+        let (to_t, mut to_r, to_s) = other.decompose_trs()?;
+        if from_r.dot(to_r) < F::ZERO {
+            to_r *= -F::ONE;
+        }
+
+        Some(Self::from_trs(
+            from_t.lerp(to_t, t),
+            from_r.slerp(to_r, t),
+            from_s.lerp(to_s, t),
+        ))
+    }
+
+    /// Multiplies all `matrices` left to right, i.e. `product(&[a, b, c])` = `a * b * c`.
+    /// Since matrices transform column vectors, the last matrix is applied first, so a
+    /// view-projection-model chain is passed as `&[projection, view, model]`.
+    /// Returns `IDENTITY` if `matrices` is empty.
+    pub fn product(matrices: &[Self]) -> Self {
+        matrices.iter().fold(Self::IDENTITY, |acc, m| acc * *m)
+    }
+
+    /// Decomposes the matrix into translation, rotation and scale, such that the matrix is equal
+    /// to `T * R * S`. Negative determinant is represented by negative `x` scale.
+    /// Returns `None` if the matrix has perspective, shear or zero scale, i.e. it can't be
+    /// represented by these components.
+    pub fn decompose_trs(&self) -> Option<(Vector3<F>, Quaternion<F>, Vector3<F>)> {
+        if !(self.row4 - Vector4::W).is_zero() {
+            return None;
+        }
+
+        let linear = self.truncate();
+        let mut scale = Vector3::new(
+            linear.column(1).magnitude(),
+            linear.column(2).magnitude(),
+            linear.column(3).magnitude(),
+        );
+        if scale.min_element() < F::EPSILON {
+            return None;
+        }
+        if !linear.is_right_handed() {
+            scale.x = -scale.x;
+        }
+
+        let (x, y, z) = (
+            linear.column(1) / scale.x,
+            linear.column(2) / scale.y,
+            linear.column(3) / scale.z,
+        );
+        let tolerance = F::EPSILON.sqrt();
+        if x.dot(y).abs() > tolerance || x.dot(z).abs() > tolerance || y.dot(z).abs() > tolerance {
+            return None;
+        }
+
+        let translation = Vector3::new(self.row1.w, self.row2.w, self.row3.w);
+        let rotation = Quaternion::from_matrix3(Matrix3::from_columns(x, y, z));
+
+        Some((translation, rotation, scale))
+    }
+
+    /// Transforms a point, treating it as `(x, y, z, 1)` and performing the perspective divide.
+    pub fn transform_point(&self, p: Vector3<F>) -> Vector3<F> {
+        let v = *self * p.extend(F::ONE);
+
+        v.truncate() / v.w
+    }
+
+    /// Transforms a point, assuming the matrix is affine, i.e. its last row is `0, 0, 0, 1`.
+    /// Skips the perspective divide. See [`Self::transform_point`].
+    pub fn transform_point_affine(&self, p: Vector3<F>) -> Vector3<F> {
+        debug_assert!(
+            self.row4 == Vector4::W,
+            "Matrix is not affine, last row: {:?}",
+            self.row4
+        );
+
+        Vector3 {
+            x: self.row1.truncate().dot(p) + self.row1.w,
+            y: self.row2.truncate().dot(p) + self.row2.w,
+            z: self.row3.truncate().dot(p) + self.row3.w,
+        }
+    }
+
+    /// Transforms plane coefficients `(a, b, c, d)` by the matrix. Planes transform with the
+    /// inverse transpose of the matrix, like normals.
+    /// # Panics
+    /// If the matrix is not invertible.
+    pub fn transform_plane(&self, plane: Vector4<F>) -> Vector4<F> {
+        self.inversed().transform_plane_by_inverse(plane)
+    }
 
-        copy.diagonal().product()
+    /// Transforms plane coefficients `(a, b, c, d)`, treating `self` as an already inverted
+    /// transform. Useful to transform many planes without inverting the matrix every time.
+    /// See [`Self::transform_plane`].
+    pub fn transform_plane_by_inverse(&self, plane: Vector4<F>) -> Vector4<F> {
+        self.transposed() * plane
     }
 }
 
@@ -313,4 +637,4 @@ unsafe impl<F: Float> bytemuck::Pod for Matrix4<F> {}
 #[cfg(feature = "bytemuck")]
 unsafe impl<F: Float> bytemuck::Zeroable for Matrix4<F> {}
 
-crate::__impl_mat_ops!(Matrix4, Vector4, 4, row1, row2, row3, row4);
+crate::__impl_mat_ops!(Matrix4, Vector4, 4, row1: X, row2: Y, row3: Z, row4: W);
@@ -0,0 +1,75 @@
+use crate::{Float, Matrix4, Vector3, Vector4};
+
+/// Single precision plane.
+pub type Planef = Plane<f32>;
+/// Double precision plane.
+pub type Planed = Plane<f64>;
+
+/// Plane defined by equation `ax + by + cz + d = 0`, stored as `(a, b, c, d)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct Plane<F: Float>(pub Vector4<F>);
+
+impl<F: Float> Plane<F> {
+    /// Creates a plane going through `point` with `normal`.
+    #[inline]
+    pub fn from_point_normal(point: Vector3<F>, normal: Vector3<F>) -> Self {
+        Self(normal.extend(-normal.dot(point)))
+    }
+
+    /// Creates a plane going through three points. Normal points towards the side from which
+    /// `a`, `b`, `c` appear counter-clockwise.
+    #[inline]
+    pub fn from_points(a: Vector3<F>, b: Vector3<F>, c: Vector3<F>) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a).normalized())
+    }
+
+    /// Returns normal of the plane, i.e. `(a, b, c)`.
+    #[inline]
+    pub const fn normal(&self) -> Vector3<F> {
+        self.0.truncate()
+    }
+
+    /// Computes signed distance from the plane to `point`. It is positive if the point is on the
+    /// side the normal points to.
+    #[inline]
+    pub fn signed_distance(&self, point: Vector3<F>) -> F {
+        self.0.dot_point(point) / self.normal().magnitude()
+    }
+
+    /// Projects `point` onto the plane.
+    #[inline]
+    pub fn project_point(&self, point: Vector3<F>) -> Vector3<F> {
+        let normal = self.normal();
+
+        point - normal * (self.0.dot_point(point) / normal.sqr_magnitude())
+    }
+
+    /// Returns a copy of the plane with normalized normal.
+    #[inline]
+    pub fn normalized(self) -> Self {
+        Self(self.0 / self.normal().magnitude())
+    }
+
+    /// Returns a copy of the plane transformed by `matrix`. See [`Matrix4::transform_plane`].
+    /// # Panics
+    /// If the matrix is not invertible.
+    #[inline]
+    pub fn transformed(self, matrix: &Matrix4<F>) -> Self {
+        Self(matrix.transform_plane(self.0))
+    }
+}
+
+impl<F: Float> From<Vector4<F>> for Plane<F> {
+    #[inline]
+    fn from(value: Vector4<F>) -> Self {
+        Self(value)
+    }
+}
+
+impl<F: Float> From<Plane<F>> for Vector4<F> {
+    #[inline]
+    fn from(value: Plane<F>) -> Self {
+        value.0
+    }
+}
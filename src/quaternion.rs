@@ -1,4 +1,4 @@
-use crate::{matrix, Euler, Float, Matrix3, Rad, Vector3};
+use crate::{matrix, vector, Euler, EulerOrder, Float, Matrix3, Matrix4, Rad, Vector3};
 use std::{
     fmt,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
@@ -13,6 +13,14 @@ pub struct Quaternion<F: Float> {
     pub vector: Vector3<F>,
 }
 
+impl<F: Float> Quaternion<F> {
+    /// Quaternion representing no rotation.
+    pub const IDENTITY: Self = Self {
+        scalar: F::ONE,
+        vector: Vector3::ZERO,
+    };
+}
+
 impl<F: Float> Quaternion<F> {
     /// Creates a new quaternion from individual elements.
     #[inline]
@@ -33,6 +41,24 @@ impl<F: Float> Quaternion<F> {
         }
     }
 
+    /// Creates the shortest rotation that rotates direction `from` to direction `to`.
+    /// If directions are opposite, rotation is by `pi` around an arbitrary perpendicular axis.
+    pub fn from_rotation_arc(from: Vector3<F>, to: Vector3<F>) -> Self {
+        let (from, to) = (from.normalized(), to.normalized());
+
+        let dot = from.dot(to);
+        if dot + F::ONE < F::EPSILON {
+            let mut axis = from.cross(Vector3::X);
+            if axis.is_zero() {
+                axis = from.cross(Vector3::Y);
+            }
+
+            return Self::new_axis_angle(axis.normalized(), F::PI);
+        }
+
+        Self::new(F::ONE + dot, from.cross(to)).normalized()
+    }
+
     // Creates a rotation that rotates forward vector to face `target` from position `from`,
     // aligned upwards.
     // pub fn new_look_at(target: Vector3<F>, eye: Vector3<F>) -> Self {
@@ -63,7 +89,8 @@ impl<F: Float> Quaternion<F> {
             self.vector.magnitude().atan2(self.scalar) * F::TWO,
         )
     }
-    /// Converts quaternion into euler angles.
+    /// Converts quaternion into euler angles, assuming [`EulerOrder::XYZ`] order. See
+    /// [`Self::from_euler`].
     #[inline]
     pub fn into_euler(&self) -> Euler<Rad, F> {
         Euler::new(
@@ -77,25 +104,88 @@ impl<F: Float> Quaternion<F> {
         )
     }
 
-    /// Converts euler angles to quaternion.
+    /// Converts euler angles to quaternion. Rotations are applied in [`EulerOrder::XYZ`] order,
+    /// i.e. pitch around `X`, then roll around `Y` and then yaw around `Z`.
     #[inline]
     pub fn from_euler(angles: Euler<Rad, F>) -> Self {
-        let half = angles / F::TWO;
+        Self::from_euler_axes(angles.yaw, angles.pitch, angles.roll, EulerOrder::XYZ)
+    }
+
+    /// Creates a quaternion by composing rotations by `yaw` around `Z` axis, `pitch` around `X`
+    /// axis and `roll` around `Y` axis in radians, applied in the specified `order`.
+    pub fn from_euler_axes(yaw: F, pitch: F, roll: F, order: EulerOrder) -> Self {
+        let x = Self::new_axis_angle(Vector3::X, pitch);
+        let y = Self::new_axis_angle(Vector3::Y, roll);
+        let z = Self::new_axis_angle(Vector3::Z, yaw);
+
+        match order {
+            EulerOrder::XYZ => z * y * x,
+            EulerOrder::XZY => y * z * x,
+            EulerOrder::YXZ => z * x * y,
+            EulerOrder::YZX => x * z * y,
+            EulerOrder::ZXY => y * x * z,
+            EulerOrder::ZYX => x * y * z,
+        }
+    }
 
-        Self {
-            scalar: half.pitch.cos() * half.roll.cos() * half.yaw.cos()
-                + half.pitch.sin() * half.roll.sin() * half.yaw.sin(),
-            vector: Vector3 {
-                x: half.pitch.sin() * half.roll.cos() * half.yaw.cos()
-                    - half.pitch.cos() * half.roll.sin() * half.yaw.sin(),
-                y: half.pitch.cos() * half.roll.sin() * half.yaw.cos()
-                    + half.pitch.sin() * half.roll.cos() * half.yaw.sin(),
-                z: half.pitch.cos() * half.roll.cos() * half.yaw.sin()
-                    - half.pitch.sin() * half.roll.sin() * half.yaw.cos(),
-            },
+    /// Converts a rotation matrix to a quaternion representing the same rotation.
+    pub fn from_matrix3(m: Matrix3<F>) -> Self {
+        let trace = m.trace();
+        let quarter = F::ONE / (F::TWO * F::TWO);
+
+        if trace > F::ZERO {
+            let s = (trace + F::ONE).sqrt() * F::TWO;
+
+            Self::new(
+                s * quarter,
+                vector!(
+                    (m.row3.y - m.row2.z) / s,
+                    (m.row1.z - m.row3.x) / s,
+                    (m.row2.x - m.row1.y) / s,
+                ),
+            )
+        } else if m.row1.x > m.row2.y && m.row1.x > m.row3.z {
+            let s = (F::ONE + m.row1.x - m.row2.y - m.row3.z).sqrt() * F::TWO;
+
+            Self::new(
+                (m.row3.y - m.row2.z) / s,
+                vector!(
+                    s * quarter,
+                    (m.row1.y + m.row2.x) / s,
+                    (m.row1.z + m.row3.x) / s,
+                ),
+            )
+        } else if m.row2.y > m.row3.z {
+            let s = (F::ONE + m.row2.y - m.row1.x - m.row3.z).sqrt() * F::TWO;
+
+            Self::new(
+                (m.row1.z - m.row3.x) / s,
+                vector!(
+                    (m.row1.y + m.row2.x) / s,
+                    s * quarter,
+                    (m.row2.z + m.row3.y) / s,
+                ),
+            )
+        } else {
+            let s = (F::ONE + m.row3.z - m.row1.x - m.row2.y).sqrt() * F::TWO;
+
+            Self::new(
+                (m.row2.x - m.row1.y) / s,
+                vector!(
+                    (m.row1.z + m.row3.x) / s,
+                    (m.row2.z + m.row3.y) / s,
+                    s * quarter,
+                ),
+            )
         }
     }
 
+    /// Extracts rotation from the upper-left 3x3 part of a transform matrix, which is
+    /// orthonormalized first to strip scale. See [`Matrix4::rotation_only`].
+    pub fn from_matrix4(m: Matrix4<F>) -> Self {
+        Self::from_matrix3(m.rotation_only())
+    }
+
     /// Creates a new quaternion with vector part equal to `vector` and scalar part to `0`.
     #[inline]
     pub fn from_vector(vector: Vector3<F>) -> Self {
@@ -105,6 +195,22 @@ impl<F: Float> Quaternion<F> {
         }
     }
 
+    /// Converts the quaternion to an array in `[x, y, z, w]` order, where `w` is the scalar part.
+    /// Same order as used by glTF and most graphics APIs.
+    #[inline]
+    pub fn to_array(&self) -> [F; 4] {
+        [self.vector.x, self.vector.y, self.vector.z, self.scalar]
+    }
+
+    /// Converts an array in `[x, y, z, w]` order to a quaternion. See [`Self::to_array`].
+    #[inline]
+    pub fn from_array([x, y, z, w]: [F; 4]) -> Self {
+        Self {
+            scalar: w,
+            vector: Vector3 { x, y, z },
+        }
+    }
+
     /// Returns the conjugate of the quaternion.
     /// `a + bi + cj + dk` -> `a - bi - cj - dk`
     #[inline]
@@ -115,10 +221,19 @@ impl<F: Float> Quaternion<F> {
         }
     }
 
+    /// Compares quaternions lexicographically, scalar part first, using total order of floats.
+    /// See [`Vector3::total_cmp`].
+    #[inline]
+    pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.scalar
+            .total_cmp(&other.scalar)
+            .then_with(|| self.vector.total_cmp(&other.vector))
+    }
+
     /// Computes squared norm of the quaternion.
     #[inline]
     pub fn sqr_norm(&self) -> F {
-        self.scalar * self.scalar + self.vector.sqr_magnitude()
+        self.scalar.square() + self.vector.sqr_magnitude()
     }
 
     /// Computes norm of the quaternion.
@@ -136,6 +251,13 @@ impl<F: Float> Quaternion<F> {
         self.vector /= norm;
     }
 
+    /// Checks if the norm of the quaternion is `1` with regard to the square root of `EPSILON`,
+    /// which leaves room for the error accumulated by a few operations.
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        (self.sqr_norm() - F::ONE).abs() < F::EPSILON.sqrt()
+    }
+
     /// Returns normalized copy of the quaternion. See [`Self::normalize`]
     #[inline]
     pub fn normalized(self) -> Self {
@@ -147,6 +269,43 @@ impl<F: Float> Quaternion<F> {
         }
     }
 
+    /// Computes the angle in radians of the rotation represented by the quaternion, without
+    /// extracting its axis. Quaternion is expected to be normalized. Output range is: `[0, pi]`.
+    #[inline]
+    pub fn angle(&self) -> F {
+        F::TWO * self.scalar.abs().min(F::ONE).acos()
+    }
+
+    /// Computes the dot product of two quaternions treated as 4D vectors.
+    #[inline]
+    pub fn dot(&self, other: Self) -> F {
+        self.scalar * other.scalar + self.vector.dot(other.vector)
+    }
+
+    /// Computes the angle in radians of the rotation needed to go from `self` to `other`.
+    /// Both quaternions are expected to be normalized. Output range is: `[0, pi]`.
+    #[inline]
+    pub fn angle_between(&self, other: Self) -> F {
+        F::TWO * self.dot(other).abs().min(F::ONE).acos()
+    }
+
+    /// Computes the geodesic distance between two orientations. Same as
+    /// [`Self::angle_between`].
+    #[inline]
+    pub fn geodesic_distance(&self, other: Self) -> F {
+        self.angle_between(other)
+    }
+
+    /// Computes the euclidean distance between two quaternions treated as 4D vectors, taking
+    /// into account that `q` and `-q` represent the same orientation.
+    #[inline]
+    pub fn chordal_distance(&self, other: Self) -> F {
+        let diff = (*self - other).norm();
+        let sum = (*self + other).norm();
+
+        diff.min(sum)
+    }
+
     /// Computes the reciprocal of the quaternion.
     #[inline]
     pub fn reciprocal(self) -> Self {
@@ -178,18 +337,40 @@ impl<F: Float> Quaternion<F> {
     /// Computes the exponent raised to a quaternion power.
     pub fn exp(self) -> Self {
         let mag = self.vector.magnitude();
+        // `sin(x) / x` is replaced with its Taylor series near zero, where the division would
+        // lose precision or result in `NaN`.
+        let sinc = if mag < F::EPSILON.sqrt() {
+            F::ONE - mag.square() / (F::TWO * (F::ONE + F::TWO))
+        } else {
+            mag.sin() / mag
+        };
 
         Self {
             scalar: mag.cos(),
-            vector: self.vector / mag * mag.sin(),
+            vector: self.vector * sinc,
         } * self.scalar.exp()
     }
 
     /// Computes the natural logarithm of the quaternion.
+    /// The vector part of the logarithm of a negative real quaternion is zero, even though
+    /// its direction is arbitrary.
     pub fn ln(self) -> Self {
+        let norm = self.norm();
+        let mag = self.vector.magnitude();
+        // Same as `atan2(mag, scalar) / mag`, but `atan(x) / x` is replaced with its Taylor
+        // series if the vector part is small relative to the norm.
+        let factor = if self.scalar > F::ZERO && mag < F::EPSILON.sqrt() * norm {
+            let x = mag / self.scalar;
+            (F::ONE - x.square() / (F::ONE + F::TWO)) / self.scalar
+        } else if mag > F::ZERO {
+            mag.atan2(self.scalar) / mag
+        } else {
+            F::ZERO
+        };
+
         Self {
-            scalar: self.norm().ln(),
-            vector: self.vector.normalized() * (self.scalar / self.norm()).acos(),
+            scalar: norm.ln(),
+            vector: self.vector * factor,
         }
     }
 
@@ -208,11 +389,115 @@ impl<F: Float> Quaternion<F> {
         self.lerp(end, t).normalized()
     }
 
-    /// Spherically interpolates quaternions.
+    /// Returns a normalized copy of linear interpolation, approximating the normalization with
+    /// a refined inverse square root instead of `sqrt` and division.
+    /// The approximation is accurate when both quaternions are normalized and close to each
+    /// other, error grows as the angle between them approaches `pi`.
+    /// Only enabled with `fast-math` feature, otherwise same as [`Self::nlerp`].
+    pub fn nlerp_fast(self, end: Self, t: F) -> Self {
+        #[cfg(feature = "fast-math")]
+        {
+            let q = self.lerp(end, t);
+            let sqr_norm = q.sqr_norm();
+
+            let half = F::ONE / F::TWO;
+            let three_halves = F::ONE + half;
+
+            // First order approximation around 1, then one Newton-Raphson iteration.
+            let mut inv = three_halves - half * sqr_norm;
+            inv *= three_halves - half * sqr_norm * inv * inv;
+
+            q * inv
+        }
+
+        #[cfg(not(feature = "fast-math"))]
+        self.nlerp(end, t)
+    }
+
+    /// Spherically interpolates quaternions. Both of them are expected to be normalized, see
+    /// [`Self::slerp_normalized`] otherwise.
     pub fn slerp(self, end: Self, t: F) -> Self {
+        debug_assert!(
+            self.is_normalized() && end.is_normalized(),
+            "Slerp operands are not normalized, norms: {}, {}",
+            self.norm(),
+            end.norm()
+        );
+
         self * (self.reciprocal() * end).powf(t)
     }
 
+    /// Normalizes both quaternions and spherically interpolates them. See [`Self::slerp`].
+    pub fn slerp_normalized(self, end: Self, t: F) -> Self {
+        self.normalized().slerp(end.normalized(), t)
+    }
+
+    /// Computes weighted average of rotations. Every quaternion is flipped to the hemisphere of
+    /// the first one, then they are summed and normalized.
+    /// This is an approximation that is only valid when rotations are close to each other.
+    /// Returns `None` if `quats` is empty or the weighted sum is zero.
+    pub fn blend(quats: &[(Self, F)]) -> Option<Self> {
+        let (first, _) = *quats.first()?;
+
+        let sum = quats
+            .iter()
+            .fold(Self::from_vector(Vector3::ZERO), |acc, &(q, w)| {
+                if first.dot(q) < F::ZERO {
+                    acc + q * -w
+                } else {
+                    acc + q * w
+                }
+            });
+
+        if sum.sqr_norm() < F::EPSILON {
+            None
+        } else {
+            Some(sum.normalized())
+        }
+    }
+
+    /// Rotates towards `target` by at most `max_radians` along the shortest arc. If `target` is
+    /// within reach, it is returned exactly.
+    pub fn rotate_towards(self, target: Self, max_radians: F) -> Self {
+        let angle = self.angle_between(target);
+        if angle <= max_radians || angle < F::EPSILON {
+            return target;
+        }
+
+        let target = if self.dot(target) < F::ZERO {
+            target * -F::ONE
+        } else {
+            target
+        };
+
+        self.slerp(target, max_radians / angle)
+    }
+
+    /// Rotates every point in `points` by the quaternion and writes them to `out`. Rotation
+    /// matrix is computed once, which is cheaper than [`Vector3::rotated_by`] for many points.
+    /// # Panics
+    /// If `points` and `out` have different lengths.
+    pub fn rotate_slice(&self, points: &[Vector3<F>], out: &mut [Vector3<F>]) {
+        assert_eq!(
+            points.len(),
+            out.len(),
+            "Input and output slices have different lengths"
+        );
+
+        let m = self.normalized().into_matrix3();
+        for (p, o) in points.iter().zip(out) {
+            *o = m * *p;
+        }
+    }
+
+    /// Rotates every point in `points` by the quaternion in place. See [`Self::rotate_slice`].
+    pub fn rotate_slice_in_place(&self, points: &mut [Vector3<F>]) {
+        let m = self.normalized().into_matrix3();
+        for p in points {
+            *p = m * *p;
+        }
+    }
+
     /// Converts a quaternion representing rotation to a matrix representing the same rotation.
     pub fn into_matrix3(self) -> Matrix3<F> {
         matrix!(
@@ -242,6 +527,32 @@ impl<F: Float> Quaternion<F> {
     }
 }
 
+#[cfg(feature = "rand")]
+impl<F: Float> Quaternion<F>
+where
+    rand::distributions::Standard: rand::distributions::Distribution<F>,
+{
+    /// Samples a random rotation that rotates `axis` onto a direction uniformly distributed
+    /// within the cone of `max_angle` radians around it.
+    pub fn random_in_cone(axis: Vector3<F>, max_angle: F, rng: &mut impl rand::Rng) -> Self {
+        let axis = axis.normalized();
+        let mut tangent = axis.cross(Vector3::X);
+        if tangent.is_zero() {
+            tangent = axis.cross(Vector3::Y);
+        }
+        let tangent = tangent.normalized();
+        let bitangent = axis.cross(tangent);
+
+        let (u, v): (F, F) = (rng.gen(), rng.gen());
+        let cos_theta = F::ONE - u * (F::ONE - max_angle.cos());
+        let sin_theta = (F::ONE - cos_theta * cos_theta).max(F::ZERO).sqrt();
+        let phi = F::TWO * F::PI * v;
+
+        let dir = axis * cos_theta + (tangent * phi.cos() + bitangent * phi.sin()) * sin_theta;
+        Self::from_rotation_arc(axis, dir)
+    }
+}
+
 impl<F: Float> From<(Vector3<F>, F)> for Quaternion<F> {
     /// Converts from axis, angle to quaternion.
     fn from((axis, angle): (Vector3<F>, F)) -> Self {
@@ -355,3 +666,92 @@ impl<F: Float> fmt::Debug for Quaternion<F> {
             .finish()
     }
 }
+
+/// Finds the rotation that best aligns point set `from` to point set `to` in the least squares
+/// sense, after both of them are centered at their centroids (Kabsch problem).
+/// Points are matched by index. The rotation is found using Horn's method, i.e. as the
+/// eigenvector of the largest eigenvalue of a symmetric 4x4 matrix built from cross-covariance
+/// of the point sets.
+/// Returns `None` if slices are empty or have different lengths.
+pub fn kabsch<F: Float>(from: &[Vector3<F>], to: &[Vector3<F>]) -> Option<Quaternion<F>> {
+    if from.is_empty() || from.len() != to.len() {
+        return None;
+    }
+
+    let mut count = F::ZERO;
+    let (mut from_center, mut to_center) = (Vector3::ZERO, Vector3::ZERO);
+    for (a, b) in from.iter().zip(to) {
+        count += F::ONE;
+        from_center += *a;
+        to_center += *b;
+    }
+    from_center /= count;
+    to_center /= count;
+
+    let mut cov = Matrix3::ZERO;
+    for (a, b) in from.iter().zip(to) {
+        let (a, b) = (*a - from_center, *b - to_center);
+        cov += Matrix3::from_rows(b * a.x, b * a.y, b * a.z);
+    }
+
+    let [sxx, sxy, sxz] = cov.row1.to_array();
+    let [syx, syy, syz] = cov.row2.to_array();
+    let [szx, szy, szz] = cov.row3.to_array();
+
+    #[rustfmt::skip]
+    let mut sym: Matrix4<F> = matrix!(
+        sxx + syy + szz, syz - szy, szx - sxz, sxy - syx,
+        syz - szy, sxx - syy - szz, sxy + syx, szx + sxz,
+        szx - sxz, sxy + syx, syy - sxx - szz, syz + szy,
+        sxy - syx, szx + sxz, syz + szy, szz - sxx - syy,
+    );
+    let mut vecs = Matrix4::IDENTITY;
+
+    // Cyclic Jacobi eigenvalue algorithm, `sym` converges to a diagonal matrix of eigenvalues
+    // and columns of `vecs` to corresponding eigenvectors.
+    for _ in 0..32 {
+        let mut off_diagonal = F::ZERO;
+        for p in 0..3 {
+            for q in p + 1..4 {
+                off_diagonal += sym[p][q].square();
+            }
+        }
+        if off_diagonal <= F::EPSILON * F::EPSILON {
+            break;
+        }
+
+        for p in 0..3 {
+            for q in p + 1..4 {
+                if sym[p][q] == F::ZERO {
+                    continue;
+                }
+
+                let theta = (sym[q][q] - sym[p][p]) / (F::TWO * sym[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta.square() + F::ONE).sqrt());
+                let cos = F::ONE / (t.square() + F::ONE).sqrt();
+                let sin = t * cos;
+
+                for k in 0..4 {
+                    let (kp, kq) = (sym[k][p], sym[k][q]);
+                    sym[k][p] = cos * kp - sin * kq;
+                    sym[k][q] = sin * kp + cos * kq;
+                }
+                for k in 0..4 {
+                    let (pk, qk) = (sym[p][k], sym[q][k]);
+                    sym[p][k] = cos * pk - sin * qk;
+                    sym[q][k] = sin * pk + cos * qk;
+                }
+                for k in 0..4 {
+                    let (kp, kq) = (vecs[k][p], vecs[k][q]);
+                    vecs[k][p] = cos * kp - sin * kq;
+                    vecs[k][q] = sin * kp + cos * kq;
+                }
+            }
+        }
+    }
+
+    let i = sym.diagonal().max_index();
+    let [w, x, y, z] = vecs.column(i + 1).to_array();
+
+    Some(Quaternion::new(w, vector!(x, y, z)).normalized())
+}
@@ -52,6 +52,36 @@ impl<F: Float> Vector2<F> {
         }
     }
 
+    /// Computes scalar cross product between two vectors, i.e. `z` component of the cross
+    /// product of the vectors extended to 3D.
+    /// It is positive if `other` is counter-clockwise from `self` and its absolute value is
+    /// equal to the area of the parallelogram formed by two vectors.
+    #[inline]
+    pub fn cross(&self, other: Self) -> F {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Computes perpendicular dot product. Same as [`Self::cross`].
+    #[inline]
+    pub fn perp_dot(&self, other: Self) -> F {
+        self.cross(other)
+    }
+
+    /// Computes the wedge(exterior) product of two vectors. In 2D the resulting bivector has a
+    /// single component which is equal to [`Self::cross`].
+    #[inline]
+    pub fn wedge(&self, other: Self) -> F {
+        self.cross(other)
+    }
+
+    /// Returns signed angle in radians from `self` to `other`, positive if `other` is
+    /// counter-clockwise from `self`. Output range is: `(-pi, pi]`.
+    /// Same as `arc_angle_to` but without wrapping negative angles to `[pi, 2pi)`.
+    #[inline]
+    pub fn signed_angle_to(&self, other: Self) -> F {
+        self.cross(other).atan2(self.dot(other))
+    }
+
     /// Rotates angle around origin by some angle `angle` in radians counter-clockwise.
     #[inline]
     pub fn rotate_by(&mut self, angle: F) {
@@ -64,6 +94,18 @@ impl<F: Float> Vector2<F> {
         self * Complex::from_angle(angle)
     }
 
+    /// Rotates the vector around `pivot` by some angle `angle` in radians counter-clockwise.
+    #[inline]
+    pub fn rotate_around(&mut self, pivot: Self, angle: F) {
+        *self = self.rotated_around(pivot, angle)
+    }
+
+    /// Returns a rotated copy of a vector. See [`Self::rotate_around`].
+    #[inline]
+    pub fn rotated_around(self, pivot: Self, angle: F) -> Self {
+        (self - pivot).rotated_by(angle) + pivot
+    }
+
     /// Rotates angle around origin by some angle `angle` in radians clockwise.
     #[inline]
     pub fn rotate_by_clockwise(&mut self, angle: F) {
@@ -110,8 +152,10 @@ impl<F: Float> Vector2<F> {
             .unwrap()
     }
 
+    /// Mirrors the vector across a line going through origin along `axis` direction.
+    /// Unlike [`Self::reflect`], `axis` lies on the mirror line rather than being its normal.
     #[inline]
-    pub fn reflect(&self, axis: Self) -> Self {
+    pub fn reflect_across_axis(&self, axis: Self) -> Self {
         self.projected_onto(axis) * F::TWO - *self
     }
 }
@@ -145,3 +189,7 @@ unsafe impl<F: Float> bytemuck::Zeroable for Vector2<F> {}
 
 crate::__impl_vec_ops!(Vector2, 1, x, y);
 crate::__impl_planar_ops!(Vector2, [x, 0, F], [y, 1, F]);
+crate::__impl_swizzles!(
+    Vector2 => Vector2,
+    xy: [x, y], yx: [y, x],
+);
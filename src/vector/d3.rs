@@ -15,6 +15,26 @@ pub struct Vector3<F: Float> {
     pub z: F,
 }
 
+/// Axis of 3 dimensional space, used to access [`Vector3`] components by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis3 {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis3 {
+    /// Returns unit vector pointing along the axis.
+    #[inline]
+    pub const fn unit_vector<F: Float>(self) -> Vector3<F> {
+        match self {
+            Self::X => Vector3::X,
+            Self::Y => Vector3::Y,
+            Self::Z => Vector3::Z,
+        }
+    }
+}
+
 impl<F: Float> Vector3<F> {
     pub const ZERO: Self = Self::new(F::ZERO, F::ZERO, F::ZERO);
     pub const ONE: Self = Self::new(F::ONE, F::ONE, F::ONE);
@@ -30,6 +50,26 @@ impl<F: Float> Vector3<F> {
 }
 
 impl<F: Float> Vector3<F> {
+    /// Returns the component along `axis`.
+    #[inline]
+    pub const fn get(&self, axis: Axis3) -> F {
+        match axis {
+            Axis3::X => self.x,
+            Axis3::Y => self.y,
+            Axis3::Z => self.z,
+        }
+    }
+
+    /// Sets the component along `axis`.
+    #[inline]
+    pub fn set(&mut self, axis: Axis3, value: F) {
+        match axis {
+            Axis3::X => self.x = value,
+            Axis3::Y => self.y = value,
+            Axis3::Z => self.z = value,
+        }
+    }
+
     /// Extends the vector with `w` component to create a [`Vector4`].
     pub const fn extend(self, w: F) -> Vector4<F> {
         Vector4 {
@@ -40,6 +80,11 @@ impl<F: Float> Vector3<F> {
         }
     }
 
+    /// Converts a point to homogeneous coordinates, extending it with `w` = `1`.
+    pub const fn homogenize(self) -> Vector4<F> {
+        self.extend(F::ONE)
+    }
+
     /// Truncates vector to [`Vector2`], removing `z` component.
     pub const fn truncate(self) -> Vector2<F> {
         Vector2 {
@@ -59,6 +104,13 @@ impl<F: Float> Vector3<F> {
         }
     }
 
+    /// Computes the wedge(exterior) product of two vectors. In 3D the resulting bivector is
+    /// represented by its dual vector, which is equal to [`Self::cross`].
+    #[inline]
+    pub fn wedge(&self, other: Self) -> Self {
+        self.cross(other)
+    }
+
     /// Computes triple product between three vectors.
     /// Triple product is a signed volume of a parallelopiped formed by three vectors.
     #[inline]
@@ -84,6 +136,33 @@ impl<F: Float> Vector3<F> {
             .vector
     }
 
+    /// Rotates the vector around `pivot` point about `axis` on `angle` in radians.
+    /// To avoid unexpected results, use normalized axis.
+    #[inline]
+    pub fn rotate_around(&mut self, pivot: Self, axis: Self, angle: F) {
+        *self = self.rotated_around(pivot, axis, angle);
+    }
+
+    /// Returns a rotated copy of the vector. See [`Self::rotate_around`].
+    #[inline]
+    pub fn rotated_around(self, pivot: Self, axis: Self, angle: F) -> Self {
+        (self - pivot).rotated_by(Quaternion::new_axis_angle(axis, angle)) + pivot
+    }
+
+    /// Spherically interpolates between two directions by rotating `self` towards `target`
+    /// along the shortest arc. Magnitude of `self` is preserved.
+    /// Unlike [`Self::slerp`] it is stable for nearly parallel and antiparallel vectors.
+    #[inline]
+    pub fn slerp_direction(self, target: Self, t: F) -> Self {
+        let arc = Quaternion::from_rotation_arc(self, target);
+        if arc.vector.is_zero() {
+            return self;
+        }
+
+        let (axis, angle) = arc.into_axis_angle();
+        self.rotated_by(Quaternion::new_axis_angle(axis, angle * t))
+    }
+
     /// Returns maximum element of the vector.
     #[inline]
     pub fn max_element(&self) -> F {
@@ -119,6 +198,66 @@ impl<F: Float> Vector3<F> {
     }
 }
 
+/// Computes tangent and bitangent of a triangle from its positions and texture coordinates.
+/// If texture coordinates are degenerate, tangent is aligned with the first edge and bitangent
+/// is perpendicular to it in the plane of the triangle.
+pub fn compute_tangent<F: Float>(
+    p0: Vector3<F>,
+    p1: Vector3<F>,
+    p2: Vector3<F>,
+    uv0: Vector2<F>,
+    uv1: Vector2<F>,
+    uv2: Vector2<F>,
+) -> (Vector3<F>, Vector3<F>) {
+    let (e1, e2) = (p1 - p0, p2 - p0);
+    let (duv1, duv2) = (uv1 - uv0, uv2 - uv0);
+
+    let det = duv1.cross(duv2);
+    if det.abs() < F::EPSILON {
+        let normal = e1.cross(e2).normalized();
+        let tangent = e1.normalized();
+
+        return (tangent, normal.cross(tangent));
+    }
+
+    let tangent = (e1 * duv2.y - e2 * duv1.y) / det;
+    let bitangent = (e2 * duv1.x - e1 * duv2.x) / det;
+
+    (tangent, bitangent)
+}
+
+/// Computes Schlick's approximation of reflectance for a ray hitting a surface at angle whose
+/// cosine is `cos_theta`, where `eta` is the ratio of refractive indices.
+pub fn fresnel<F: Float>(cos_theta: F, eta: F) -> F {
+    let r0 = ((F::ONE - eta) / (F::ONE + eta)).square();
+
+    r0 + (F::ONE - r0) * (F::ONE - cos_theta).powi(5)
+}
+
+/// Interpolates along the polyline through `points`, where `t` in `[0, 1]` range is mapped to
+/// the total length of the polyline rather than to individual segments.
+/// Returns `None` if there are less than `2` points.
+pub fn path_lerp<F: Float>(points: &[Vector3<F>], t: F) -> Option<Vector3<F>> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let total = points
+        .windows(2)
+        .fold(F::ZERO, |acc, w| acc + w[0].distance_to(w[1]));
+    let mut remaining = t.clamp(F::ZERO, F::ONE) * total;
+
+    for w in points.windows(2) {
+        let length = w[0].distance_to(w[1]);
+        if remaining <= length && length > F::ZERO {
+            return Some(w[0].lerp(w[1], remaining / length));
+        }
+        remaining -= length;
+    }
+
+    points.last().copied()
+}
+
 #[cfg(feature = "bytemuck")]
 unsafe impl<F: Float> bytemuck::Pod for Vector3<F> {}
 #[cfg(feature = "bytemuck")]
@@ -126,3 +265,11 @@ unsafe impl<F: Float> bytemuck::Zeroable for Vector3<F> {}
 
 crate::__impl_vec_ops!(Vector3, 2, x, y, z);
 crate::__impl_planar_ops!(Vector3, [x, 0, F], [y, 1, F], [z, 2, F]);
+crate::__impl_swizzles!(
+    Vector3 => Vector2,
+    xy: [x, y], xz: [x, z], yx: [y, x], yz: [y, z], zx: [z, x], zy: [z, y],
+);
+crate::__impl_swizzles!(
+    Vector3 => Vector3,
+    xyz: [x, y, z], xzy: [x, z, y], yxz: [y, x, z], yzx: [y, z, x], zxy: [z, x, y], zyx: [z, y, x],
+);
@@ -1,4 +1,4 @@
-use crate::{Float, Vector3};
+use crate::{Float, Vector2, Vector3};
 use std::cmp::Ordering;
 
 /// Single precession Vector4.
@@ -52,6 +52,20 @@ impl<F: Float> Vector4<F> {
         }
     }
 
+    /// Converts a point from homogeneous coordinates, dividing `x`, `y`, `z` by `w`.
+    #[inline]
+    pub fn dehomogenize(self) -> Vector3<F> {
+        self.truncate() / self.w
+    }
+
+    /// Computes the dot product with a point treated as `(x, y, z, 1)`.
+    /// If the vector stores a plane equation `(a, b, c, d)` with normalized `(a, b, c)`, this is
+    /// the signed distance from the point to the plane.
+    #[inline]
+    pub fn dot_point(&self, p: Vector3<F>) -> F {
+        self.truncate().dot(p) + self.w
+    }
+
     /// Returns maximum element of the vector.
     #[inline]
     pub fn max_element(&self) -> F {
@@ -94,3 +108,24 @@ unsafe impl<F: Float> bytemuck::Zeroable for Vector4<F> {}
 
 crate::__impl_vec_ops!(Vector4, 3, x, y, z, w);
 crate::__impl_planar_ops!(Vector4, [x, 0, F], [y, 1, F], [z, 2, F], [w, 3, F]);
+crate::__impl_swizzles!(
+    Vector4 => Vector2,
+    xy: [x, y], xz: [x, z], xw: [x, w], yx: [y, x], yz: [y, z], yw: [y, w], zx: [z, x], zy: [z, y],
+    zw: [z, w], wx: [w, x], wy: [w, y], wz: [w, z],
+);
+crate::__impl_swizzles!(
+    Vector4 => Vector3,
+    xyz: [x, y, z], xyw: [x, y, w], xzy: [x, z, y], xzw: [x, z, w], xwy: [x, w, y], xwz: [x, w, z],
+    yxz: [y, x, z], yxw: [y, x, w], yzx: [y, z, x], yzw: [y, z, w], ywx: [y, w, x], ywz: [y, w, z],
+    zxy: [z, x, y], zxw: [z, x, w], zyx: [z, y, x], zyw: [z, y, w], zwx: [z, w, x], zwy: [z, w, y],
+    wxy: [w, x, y], wxz: [w, x, z], wyx: [w, y, x], wyz: [w, y, z], wzx: [w, z, x], wzy: [w, z, y],
+);
+crate::__impl_swizzles!(
+    Vector4 => Vector4,
+    xyzw: [x, y, z, w], xywz: [x, y, w, z], xzyw: [x, z, y, w], xzwy: [x, z, w, y],
+    xwyz: [x, w, y, z], xwzy: [x, w, z, y], yxzw: [y, x, z, w], yxwz: [y, x, w, z],
+    yzxw: [y, z, x, w], yzwx: [y, z, w, x], ywxz: [y, w, x, z], ywzx: [y, w, z, x],
+    zxyw: [z, x, y, w], zxwy: [z, x, w, y], zyxw: [z, y, x, w], zywx: [z, y, w, x],
+    zwxy: [z, w, x, y], zwyx: [z, w, y, x], wxyz: [w, x, y, z], wxzy: [w, x, z, y],
+    wyxz: [w, y, x, z], wyzx: [w, y, z, x], wzxy: [w, z, x, y], wzyx: [w, z, y, x],
+);
@@ -5,6 +5,22 @@ pub use d3::*;
 mod d4;
 pub use d4::*;
 
+/// Reinterprets a slice of vectors or matrices as bytes, e.g. to upload them to the GPU.
+#[cfg(feature = "bytemuck")]
+#[inline]
+pub fn slice_as_bytes<T: bytemuck::Pod>(slice: &[T]) -> &[u8] {
+    bytemuck::cast_slice(slice)
+}
+
+/// Reinterprets bytes as a slice of vectors or matrices. See [`slice_as_bytes`].
+/// # Panics
+/// If `bytes` are not aligned for `T` or their length is not a multiple of the size of `T`.
+#[cfg(feature = "bytemuck")]
+#[inline]
+pub fn bytes_as_slice<T: bytemuck::Pod>(bytes: &[u8]) -> &[T] {
+    bytemuck::cast_slice(bytes)
+}
+
 /// Creates new vector
 /// If number of elements is `2` => Vector2 is created.
 /// If number of elements is `3` => Vector3 is created.
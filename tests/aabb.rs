@@ -0,0 +1,164 @@
+#[macro_use]
+mod common;
+
+use sath::{Aabb3, Vector3};
+
+fn aabb(min: [f64; 3], max: [f64; 3]) -> Aabb3<f64> {
+    Aabb3::from_min_max(Vector3::from_array(min), Vector3::from_array(max))
+}
+
+#[test]
+fn union_all_encloses_boxes() {
+    let boxes = [
+        aabb([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]),
+        aabb([1.0, -1.0, 1.0], [3.0, 1.0, 2.5]),
+        aabb([-0.5, 0.5, -2.0], [1.0, 4.0, 0.5]),
+    ];
+    let expected = aabb([-0.5, -1.0, -2.0], [3.0, 4.0, 2.5]);
+
+    let union = Aabb3::union_all(boxes).unwrap();
+    assert_eq!((union.min, union.max), (expected.min, expected.max));
+
+    let mut merged = boxes[0];
+    merged.merge(&boxes[1]);
+    merged.merge(&boxes[2]);
+    assert_eq!((merged.min, merged.max), (expected.min, expected.max));
+
+    assert!(Aabb3::<f64>::union_all([]).is_none());
+}
+
+#[test]
+fn lerp_interpolates_min_and_max() {
+    let (a, b) = (
+        aabb([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+        aabb([2.0, -2.0, 4.0], [5.0, 3.0, 6.0]),
+    );
+
+    let start = a.lerp(b, 0.0);
+    let mid = a.lerp(b, 0.5);
+    let end = a.lerp(b, 1.0);
+
+    assert_eq!((start.min, start.max), (a.min, a.max));
+    assert_approx!(mid.min, Vector3::new(1.0, -1.0, 2.0));
+    assert_approx!(mid.max, Vector3::new(3.0, 2.0, 3.5));
+    assert_eq!((end.min, end.max), (b.min, b.max));
+}
+
+#[test]
+fn clip_segment_to_box() {
+    let b = aabb([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+
+    let (p, q) = b
+        .clip_segment(Vector3::new(-1.0, 1.0, 1.0), Vector3::new(3.0, 1.0, 1.0))
+        .unwrap();
+    assert_approx!(p, Vector3::new(0.0, 1.0, 1.0));
+    assert_approx!(q, Vector3::new(2.0, 1.0, 1.0));
+
+    let (p, q) = b
+        .clip_segment(Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 5.0, 1.0))
+        .unwrap();
+    assert_approx!(p, Vector3::new(1.0, 1.0, 1.0));
+    assert_approx!(q, Vector3::new(1.0, 2.0, 1.0));
+
+    assert!(b
+        .clip_segment(Vector3::new(3.0, 0.0, 0.0), Vector3::new(3.0, 2.0, 2.0))
+        .is_none());
+}
+
+#[test]
+fn segment_stopping_short_of_box() {
+    let b = aabb([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+    let a = Vector3::new(-3.0, 0.5, 0.5);
+
+    // The ray from `a` through these points would hit the box, the segments don't reach it.
+    assert!(!b.intersects_segment(a, Vector3::new(-1.0, 0.5, 0.5)));
+    assert!(!b.intersects_segment(a, Vector3::new(-0.001, 0.5, 0.5)));
+
+    assert!(b.intersects_segment(a, Vector3::new(0.5, 0.5, 0.5)));
+    assert!(b.intersects_segment(a, Vector3::new(5.0, 0.5, 0.5)));
+}
+
+#[test]
+fn display_includes_size() {
+    let b = aabb([1.0, -2.0, 0.5], [4.0, 3.0, 1.0]);
+
+    let text = b.to_string();
+    assert_eq!(
+        text,
+        "Aabb3 { min: (1, -2, 0.5), max: (4, 3, 1), size: (3, 5, 0.5) }"
+    );
+    assert!(format!("{b:?}").contains("size"));
+}
+
+#[test]
+fn bounding_and_inscribed_spheres() {
+    let cube = aabb([-1.0, -1.0, -1.0], [1.0, 1.0, 1.0]);
+    let (center, radius) = cube.bounding_sphere();
+    assert_approx!(center, Vector3::ZERO);
+    assert_approx!(radius, 3.0f64.sqrt());
+    assert_approx!(cube.inscribed_sphere_radius(), 1.0);
+
+    let flat = aabb([0.0, 0.0, 0.0], [4.0, 2.0, 0.0]);
+    let (center, radius) = flat.bounding_sphere();
+    assert_approx!(center, Vector3::new(2.0, 1.0, 0.0));
+    assert_approx!(radius, 5.0f64.sqrt());
+    assert_approx!(flat.inscribed_sphere_radius(), 0.0);
+}
+
+#[test]
+fn edges_connect_adjacent_corners() {
+    let b = aabb([-1.0, 0.0, 2.0], [3.0, 5.0, 4.0]);
+    let corners = b.corners();
+
+    for (i, corner) in corners.iter().enumerate() {
+        assert_eq!(*corner, b.corner(i));
+    }
+    for (a, c) in b.edges() {
+        let differing = (0..3).filter(|&i| corners[a][i] != corners[c][i]).count();
+        assert_eq!(differing, 1, "edge ({a}, {c})");
+    }
+}
+
+#[test]
+fn contains_checks_every_axis() {
+    let unit = aabb([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+
+    // Lexicographic comparison of vectors would accept this point since `x` is in range.
+    assert!(!unit.contains(Vector3::new(0.5, 5.0, 0.5)));
+    assert!(!unit.contains(Vector3::new(0.5, 0.5, -0.1)));
+    assert!(unit.contains(Vector3::new(0.5, 0.5, 0.5)));
+    assert!(unit.contains(Vector3::new(1.0, 0.0, 0.5)));
+
+    assert!(unit.contains_strict(Vector3::new(0.5, 0.5, 0.5)));
+    assert!(!unit.contains_strict(Vector3::new(1.0, 0.5, 0.5)));
+    assert!(!unit.contains_strict(Vector3::new(0.5, 5.0, 0.5)));
+}
+
+#[test]
+fn closest_point_on_surface_snaps_to_nearest_face() {
+    let b = aabb([0.0, 0.0, 0.0], [4.0, 2.0, 6.0]);
+
+    assert_eq!(
+        b.closest_point_on_surface(Vector3::new(1.0, 1.8, 3.0)),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        b.closest_point_on_surface(Vector3::new(0.5, 1.0, 3.0)),
+        Vector3::new(0.0, 1.0, 3.0)
+    );
+    assert_eq!(
+        b.closest_point_on_surface(Vector3::new(5.0, -1.0, 3.0)),
+        Vector3::new(4.0, 0.0, 3.0)
+    );
+
+    let on_face = Vector3::new(4.0, 1.0, 3.0);
+    assert_eq!(b.closest_point_on_surface(on_face), on_face);
+}
+
+#[test]
+fn is_right_checks_every_axis() {
+    assert!(aabb([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]).is_right());
+    assert!(!aabb([0.0, 0.0, 0.0], [1.0, -5.0, 0.0]).is_right());
+    assert!(!aabb([0.0, 0.0, 0.0], [1.0, 1.0, -1.0]).is_right());
+    assert!(!aabb([2.0, 0.0, 0.0], [1.0, 1.0, 1.0]).is_right());
+}
@@ -0,0 +1,53 @@
+#[macro_use]
+mod common;
+
+use sath::{Angle, Deg, Float, Measure, Rad};
+use std::f64::consts::PI;
+
+fn to_radians<F: Float, M: Measure>(angle: Angle<F, M>) -> F {
+    angle.0 * M::to_radians_factor::<F>()
+}
+
+#[test]
+fn generic_conversion_to_radians() {
+    assert_approx!(to_radians(Angle::<f64, Deg>::new(180.0)), PI);
+    assert_approx!(to_radians(Angle::<f64, Rad>::new(1.25)), 1.25);
+    assert_approx!(
+        to_radians(Angle::<f32, Deg>::new(90.0)),
+        std::f32::consts::FRAC_PI_2,
+        1e-6
+    );
+
+    assert_approx!(Deg::to_radians_factor::<f64>() * 180.0, PI);
+    assert_approx!(Rad::to_radians_factor::<f64>(), 1.0);
+    assert_approx!(Rad::to_degrees_factor::<f64>() * PI, 180.0);
+}
+
+#[test]
+fn conversions_round_trip() {
+    for degrees in [0.0, 45.0, -90.0, 123.456, 720.0] {
+        let angle = || Angle::<f64, Deg>::new(degrees);
+        assert_eq!(angle().into_degrees().0.to_bits(), degrees.to_bits());
+        assert_approx!(angle().into_radians().into_degrees().0, degrees, 1e-12);
+    }
+
+    for radians in [0.0, 1.0, -PI, 10.0] {
+        let angle = || Angle::<f64, Rad>::new(radians);
+        assert_eq!(angle().into_radians().0.to_bits(), radians.to_bits());
+        assert_approx!(angle().into_degrees().into_radians().0, radians, 1e-12);
+    }
+
+    assert_approx!(Angle::<f64, Deg>::new(180.0).into_radians().0, PI);
+}
+
+#[test]
+fn angle_ratio_and_wrapping() {
+    let ratio: f64 = Angle::<f64, Rad>::new(PI) / Angle::<f64, Rad>::new(PI / 2.0);
+    assert_approx!(ratio, 2.0);
+
+    let full = || Angle::<f64, Deg>::new(360.0);
+    assert_approx!((Angle::<f64, Deg>::new(-90.0) % full()).0, 270.0);
+    assert_approx!((Angle::<f64, Deg>::new(450.0) % full()).0, 90.0);
+    assert_approx!((Angle::<f64, Deg>::new(720.0) % 360.0).0, 0.0);
+    assert_approx!((Angle::<f64, Rad>::new(3.0 * PI) % (2.0 * PI)).0, PI);
+}
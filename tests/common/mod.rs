@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+
+use sath::{Complex, Matrix2, Matrix3, Matrix4, Quaternion, Vector2, Vector3, Vector4};
+
+/// Approximate equality used by [`assert_approx!`].
+pub trait Approx {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+impl Approx for f64 {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (self - other).abs() <= epsilon
+    }
+}
+
+impl Approx for f32 {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        ((self - other).abs() as f64) <= epsilon
+    }
+}
+
+macro_rules! impl_approx_arrays {
+    ($($t:ty),*) => {
+        $(
+            impl<F: sath::Float + Approx> Approx for $t {
+                fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    self.to_array()
+                        .iter()
+                        .zip(other.to_array().iter())
+                        .all(|(a, b)| a.approx_eq(b, epsilon))
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_arrays!(Vector2<F>, Vector3<F>, Vector4<F>, Quaternion<F>);
+
+macro_rules! impl_approx_matrices {
+    ($($t:ident: $dim:literal),*) => {
+        $(
+            impl<F: sath::Float + Approx> Approx for $t<F> {
+                fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    (0..$dim).all(|i| self[i].approx_eq(&other[i], epsilon))
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_matrices!(Matrix2: 2, Matrix3: 3, Matrix4: 4);
+
+impl<F: sath::Float + Approx> Approx for Complex<F> {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.real.approx_eq(&other.real, epsilon) && self.imag.approx_eq(&other.imag, epsilon)
+    }
+}
+
+/// Asserts that two values are equal within `1e-9` or the given epsilon.
+#[allow(unused_macros)]
+macro_rules! assert_approx {
+    ($a:expr, $b:expr $(,)?) => {
+        assert_approx!($a, $b, 1e-9)
+    };
+    ($a:expr, $b:expr, $epsilon:expr $(,)?) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            $crate::common::Approx::approx_eq(a, b, $epsilon),
+            "assertion failed: {:?} ~= {:?}",
+            a,
+            b
+        );
+    }};
+}
@@ -0,0 +1,145 @@
+#[macro_use]
+mod common;
+
+use sath::{complex, solve_quadratic, Angle, Complex, Matrix2, Rad};
+use std::f64::consts::{E, PI, TAU};
+
+#[test]
+fn slerp_stays_on_unit_circle() {
+    let (a, b) = (Complex::from_angle(0.3), Complex::from_angle(-2.9));
+
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        assert_approx!(a.slerp(b, t).magnitude(), 1.0);
+    }
+
+    // Shortest arc from 0.3 to -2.9 goes through pi.
+    assert_approx!(
+        a.slerp(b, 0.5),
+        Complex::from_angle(0.3 + (TAU - 3.2) / 2.0)
+    );
+}
+
+#[test]
+fn slerp_from_zero() {
+    let end = Complex::new(0.0, 2.0);
+    let zero = Complex::new(0.0, 0.0);
+
+    assert_approx!(zero.slerp(end, 0.5), Complex::new(0.0, 1.0));
+    assert_approx!(end.slerp(zero, 0.5), Complex::new(0.0, 1.0));
+}
+
+#[test]
+fn from_matrix2_validates_structure() {
+    let z = Complex::new(1.5, -0.5);
+
+    assert_approx!(Complex::from_matrix2(z.to_matrix2()).unwrap(), z);
+    assert_approx!(
+        Complex::from_matrix2(Matrix2::new(2.0, -3.0, 3.0, 2.0)).unwrap(),
+        Complex::new(2.0, 3.0)
+    );
+
+    assert!(Complex::from_matrix2(Matrix2::new(1.0, 2.0, 3.0, 4.0)).is_none());
+    assert!(Complex::from_matrix2(Matrix2::new(1.0, -2.0, 2.0, 1.5)).is_none());
+}
+
+#[test]
+fn sqrt_on_branch_cut() {
+    let (principal, other) = Complex::new(-4.0, 0.0).sqrt();
+    assert_approx!(principal, Complex::new(0.0, 2.0));
+    assert_approx!(other, Complex::new(0.0, -2.0));
+
+    let (principal, _) = Complex::new(-4.0, -0.0).sqrt();
+    assert_approx!(principal, Complex::new(0.0, -2.0));
+
+    let (principal, _) = Complex::<f64>::new(4.0, 0.0).sqrt();
+    assert!(principal.imag.is_sign_positive());
+    let (principal, _) = Complex::<f64>::new(4.0, -0.0).sqrt();
+    assert!(principal.imag.is_sign_negative());
+    assert_approx!(principal, Complex::new(2.0, 0.0));
+
+    let (principal, _) = Complex::new(3.0, 4.0).sqrt();
+    assert_approx!(principal, Complex::new(2.0, 1.0));
+}
+
+#[test]
+fn powc_matches_known_values() {
+    let z: Complex<f64> = complex!(1.5, -0.75);
+    assert_approx!(z.powc(complex!(2, 0)), z * z, 1e-12);
+
+    let e: Complex<f64> = complex!(E, 0);
+    assert_approx!(e.powc(complex!(0, PI)), complex!(-1, 0), 1e-12);
+}
+
+#[test]
+fn quadratic_roots() {
+    let real = |x: f64| Complex::new(x, 0.0);
+    let sorted = |(a, b): (Complex<f64>, Complex<f64>)| {
+        if (a.real, a.imag) <= (b.real, b.imag) {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+
+    // x² - 3x + 2 = (x - 1)(x - 2)
+    let (r1, r2) = sorted(solve_quadratic(real(1.0), real(-3.0), real(2.0)));
+    assert_approx!(r1, real(1.0));
+    assert_approx!(r2, real(2.0));
+
+    // x² - 4x + 4 = (x - 2)²
+    let (r1, r2) = solve_quadratic(real(1.0), real(-4.0), real(4.0));
+    assert_approx!(r1, real(2.0));
+    assert_approx!(r2, real(2.0));
+
+    // x² + 2x + 5 = (x + 1 - 2i)(x + 1 + 2i)
+    let (r1, r2) = sorted(solve_quadratic(real(1.0), real(2.0), real(5.0)));
+    assert_approx!(r1, Complex::new(-1.0, -2.0));
+    assert_approx!(r2, Complex::new(-1.0, 2.0));
+}
+
+#[test]
+fn reciprocal_of_zero() {
+    let zero = Complex::<f64>::new(0.0, 0.0);
+
+    assert!(zero.is_zero(1e-12));
+    assert!(zero.try_reciprocal().is_none());
+    assert!(Complex::new(1e-20, -1e-20).try_reciprocal().is_none());
+    assert!(zero.reciprocal().real.is_nan());
+
+    let z = Complex::new(3.0, 4.0);
+    assert_approx!(z.try_reciprocal().unwrap(), Complex::new(0.12, -0.16));
+    assert_approx!(-z + z, zero);
+}
+
+#[test]
+fn typed_polar_round_trip() {
+    for c in [
+        complex!(3.0, 4.0),
+        complex!(-1.5, 0.25),
+        complex!(0.0, -2.0),
+    ] {
+        let (magnitude, angle) = c.to_polar_typed();
+        assert_approx!(Complex::<f64>::from_polar_typed(magnitude, angle), c);
+    }
+
+    let (magnitude, angle) =
+        Complex::from_polar_typed(2.0, Angle::<f64, Rad>::new(PI / 3.0)).to_polar_typed();
+    assert_approx!(magnitude, 2.0);
+    assert_approx!(angle.0, PI / 3.0);
+}
+
+#[test]
+fn matrix2_from_angle_matches_complex() {
+    for angle in [0.0, 0.4, PI / 2.0, -2.5, PI] {
+        let m = Matrix2::from_angle(angle);
+        let c = Complex::<f64>::from_angle(angle);
+
+        assert_eq!(m, c.to_matrix2());
+        assert_approx!(Complex::from_matrix2(m).unwrap(), c);
+    }
+
+    let quarter = Matrix2::from_angle(PI / 2.0);
+    assert_approx!(quarter[1][0], 1.0);
+    assert_approx!(quarter[0][1], -1.0);
+}
@@ -0,0 +1,36 @@
+#[macro_use]
+mod common;
+
+use sath::{Deg, Euler, Measure, Quaternion, Rad, Vector3};
+
+#[test]
+fn display_has_unit_suffix() {
+    let rad = Euler::<Rad, f64>::new(1.5, 0.0, -0.5).to_string();
+    let deg = Euler::<Deg, f64>::new(90.0, 0.0, -30.0).to_string();
+
+    assert_ne!(Rad::SUFFIX, Deg::SUFFIX);
+    assert_eq!(rad, "Euler { yaw: 1.5 rad, pitch: 0 rad, roll: -0.5 rad }");
+    assert_eq!(deg, "Euler { yaw: 90°, pitch: 0°, roll: -30° }");
+}
+
+#[test]
+fn from_look_direction_faces_forward() {
+    let up = Vector3::<f64>::Z;
+    let axes = [
+        Vector3::X,
+        -Vector3::X,
+        Vector3::Y,
+        -Vector3::Y,
+        Vector3::Z,
+        -Vector3::Z,
+    ];
+
+    for forward in axes {
+        let rotation = Quaternion::from_euler(Euler::from_look_direction(forward, up));
+        assert_approx!(Vector3::Y.rotated_by(rotation), forward, 1e-6);
+
+        if forward.cross(up) != Vector3::ZERO {
+            assert_approx!(Vector3::Z.rotated_by(rotation), up, 1e-6);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use sath::{Complex, Float, Quaternion, Vector3, Vector4};
+
+#[test]
+fn square_matches_multiplication() {
+    for x in [0.0f64, -0.0, 1.5, -3.25, 1e-200, 1e200, f64::MIN_POSITIVE] {
+        assert_eq!(x.square().to_bits(), (x * x).to_bits());
+        assert_eq!(x.powi(2).to_bits(), (x * x).to_bits());
+    }
+}
+
+#[test]
+fn squared_norms_match_previous_formulas() {
+    let v = Vector3::new(0.1f64, -2.7, 3.3);
+    let w = Vector4::new(1.25f64, -0.5, 7.0, 0.3);
+    let c = Complex::new(0.7f64, -1.9);
+    let q = Quaternion::new(0.4f64, v);
+
+    assert_eq!(v.sqr_magnitude(), v.x * v.x + v.y * v.y + v.z * v.z);
+    assert_eq!(
+        w.sqr_magnitude(),
+        w.x * w.x + w.y * w.y + w.z * w.z + w.w * w.w
+    );
+    assert_eq!(c.sqr_magnitude(), c.real * c.real + c.imag * c.imag);
+    assert_eq!(
+        q.sqr_norm(),
+        q.scalar * q.scalar + (v.x * v.x + v.y * v.y + v.z * v.z)
+    );
+
+    let d = Vector3::new(-1.0f64, 0.5, 2.0) - v;
+    assert_eq!(
+        v.sqr_distance_to(Vector3::new(-1.0, 0.5, 2.0)),
+        d.x * d.x + d.y * d.y + d.z * d.z
+    );
+}
+
+fn bits_round_trip<F: Float>(x: F) -> F {
+    F::from_bits(x.to_bits())
+}
+
+#[test]
+fn bits_round_trip_exactly() {
+    for x in [0.0f64, 1.5, -3.25, 1e-300, f64::MAX, f64::INFINITY] {
+        assert_eq!(bits_round_trip(x).to_bits(), x.to_bits());
+    }
+    for x in [0.0f32, 1.5, -3.25, 1e-30, f32::MAX, f32::NEG_INFINITY] {
+        assert_eq!(bits_round_trip(x).to_bits(), x.to_bits());
+        assert_eq!(Float::to_bits(&x), x.to_bits() as u64);
+    }
+    assert!(bits_round_trip(f64::NAN).is_nan());
+
+    assert_ne!(Float::to_bits(&0.0f64), Float::to_bits(&-0.0f64));
+    assert_ne!(Float::to_bits(&0.0f32), Float::to_bits(&-0.0f32));
+    assert_ne!(
+        Vector3::new(0.0f64, 1.0, 2.0).to_bits_array(),
+        Vector3::new(-0.0f64, 1.0, 2.0).to_bits_array()
+    );
+}
+
+fn limits<F: Float>() -> (F, F, F) {
+    (F::MIN, F::MAX, F::MIN_POSITIVE)
+}
+
+#[test]
+fn generic_limits_match_primitives() {
+    assert_eq!(limits::<f32>(), (f32::MIN, f32::MAX, f32::MIN_POSITIVE));
+    assert_eq!(limits::<f64>(), (f64::MIN, f64::MAX, f64::MIN_POSITIVE));
+
+    let (min, max, min_positive) = limits::<f64>();
+    assert_eq!(min, -max);
+    assert!(min_positive > 0.0 && (min_positive / 2.0).is_subnormal());
+}
@@ -0,0 +1,30 @@
+#[macro_use]
+mod common;
+
+use sath::{mix, smoothstep, step, Vector2, Vector3};
+
+#[test]
+fn glsl_reference_values() {
+    assert_approx!(mix(2.0, 6.0, 0.25), 3.0);
+    assert_approx!(
+        mix(Vector2::new(0.0, 10.0), Vector2::new(1.0, 20.0), 0.5),
+        Vector2::new(0.5, 15.0)
+    );
+
+    assert_eq!(step(0.5, 0.49), 0.0);
+    assert_eq!(step(0.5, 0.5), 1.0);
+    assert_eq!(
+        step(Vector3::same(1.0), Vector3::new(0.0, 1.0, 2.0)),
+        Vector3::new(0.0, 1.0, 1.0)
+    );
+
+    assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+    assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+    assert_approx!(smoothstep(0.0, 1.0, 0.5), 0.5);
+    assert_approx!(smoothstep(0.0, 1.0, 0.25), 0.15625);
+    assert_approx!(smoothstep(2.0, 4.0, 3.5), 0.84375);
+    assert_approx!(
+        smoothstep(Vector2::ZERO, Vector2::ONE, Vector2::new(0.25, 0.75)),
+        Vector2::new(0.15625, 0.84375)
+    );
+}
@@ -0,0 +1,600 @@
+#[macro_use]
+mod common;
+
+use sath::{
+    Angle, Deg, DepthRange, Handedness, Matrix2, Matrix3, Matrix4, Quaternion, Rad, Vector2,
+    Vector3, Vector4,
+};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const RANGES: [DepthRange; 3] = [
+    DepthRange::NegativeOneToOne,
+    DepthRange::ZeroToOne,
+    DepthRange::OneToZero,
+];
+
+fn ndc_depth(m: &Matrix4<f64>, z: f64) -> f64 {
+    let clip = *m * Vector4::new(0.3, -0.2, z, 1.0);
+    clip.z / clip.w
+}
+
+#[test]
+fn perspective_full_maps_near_and_far() {
+    for range in RANGES {
+        let (near_depth, far_depth): (f64, f64) = range.near_far();
+
+        let finite = Matrix4::new_perspective_full(1.0, 1.5, 0.1, Some(100.0), range);
+        assert_approx!(ndc_depth(&finite, -0.1), near_depth);
+        assert_approx!(ndc_depth(&finite, -100.0), far_depth);
+
+        let infinite = Matrix4::new_perspective_full(1.0, 1.5, 0.1, None, range);
+        assert_approx!(ndc_depth(&infinite, -0.1), near_depth);
+        assert_approx!(ndc_depth(&infinite, -1e12), far_depth, 1e-9);
+    }
+}
+
+#[test]
+fn frustum_planes_match_depth_range() {
+    for range in RANGES {
+        let m = Matrix4::new_perspective_full(1.0, 1.5, 0.1, Some(100.0), range);
+        let planes = m.frustum_planes(range);
+
+        let near_point = Vector3::new(0.0, 0.0, -0.1);
+        let far_point = Vector3::new(0.0, 0.0, -100.0);
+        let inside = Vector3::new(0.1, -0.1, -10.0);
+
+        assert_approx!(planes[4].dot_point(near_point), 0.0, 1e-9);
+        assert_approx!(planes[5].dot_point(far_point), 0.0, 1e-9);
+        assert!(planes.iter().all(|p| p.dot_point(inside) > 0.0));
+    }
+}
+
+#[test]
+fn det_accounts_for_row_swaps() {
+    let m3 = Matrix3::from([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_approx!(m3.det(), -1.0);
+
+    let m4 = Matrix4::from([
+        [0.0, 1.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    assert_approx!(m4.det(), -1.0);
+}
+
+#[test]
+fn rank_of_matrices() {
+    assert_eq!(Matrix2::from([[1.0, 2.0], [2.0, 4.0]]).rank(), 1);
+    assert_eq!(Matrix2::from([[1.0, 2.0], [3.0, 4.0]]).rank(), 2);
+
+    let m = Matrix3::from([[1.0, 0.0, 0.0], [5.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(m.rank(), 2);
+}
+
+#[test]
+fn rotation_axis_near_pi() {
+    let axis = Vector3::new(0.3, -0.5, 0.8).normalized();
+
+    let half_turn = Matrix3::from_axis_angle(axis, PI).rotation_axis();
+    assert_approx!(half_turn.dot(axis).abs(), 1.0, 1e-9);
+
+    for angle in [PI - 1e-3, FRAC_PI_2 - 1e-3, FRAC_PI_2 + 1e-3, 0.5] {
+        assert_approx!(
+            Matrix3::from_axis_angle(axis, angle).rotation_axis(),
+            axis,
+            1e-9
+        );
+    }
+}
+
+#[test]
+fn exp_log_so3_round_trip() {
+    let axis = Vector3::new(-0.2, 0.9, 0.4).normalized();
+
+    for angle in [0.0, 1e-6, 1e-4, 0.7, 2.5, PI - 1e-4] {
+        let omega = axis * angle;
+        let m = Matrix3::exp_so3(omega);
+
+        assert_approx!(m, Matrix3::from_axis_angle(axis, angle), 1e-9);
+        assert_approx!(m.log_so3(), omega, 1e-9);
+    }
+}
+
+#[test]
+fn mul_assign_matches_mul() {
+    let (a2, b2) = (
+        Matrix2::from([[1.0, 2.0], [3.0, 4.0]]),
+        Matrix2::from([[0.0, 1.0], [-1.0, 5.0]]),
+    );
+    let mut c2 = a2;
+    c2 *= b2;
+    assert_approx!(c2, a2 * b2);
+    assert_ne!(a2 * b2, b2 * a2);
+
+    let a3 = Matrix3::from([[1.0, 2.0, 0.0], [0.0, 1.0, 3.0], [4.0, 0.0, 1.0]]);
+    let b3 = Matrix3::from([[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, -1.0, 2.0]]);
+    let mut c3 = a3;
+    c3 *= b3;
+    assert_approx!(c3, a3 * b3);
+    assert_ne!(a3 * b3, b3 * a3);
+
+    let a4 = Matrix4::new_translation(Vector3::new(1.0, 2.0, 3.0));
+    let b4 = Matrix4::new_scale_about(Vector3::new(2.0, 3.0, 4.0), Vector3::ONE);
+    let mut c4 = a4;
+    c4 *= b4;
+    assert_approx!(c4, a4 * b4);
+    assert_ne!(a4 * b4, b4 * a4);
+}
+
+#[test]
+fn matrix_from_arrays_and_slices() {
+    let m = Matrix2::from([[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(m.row(1), Vector2::new(1.0, 2.0));
+    assert_eq!(m.row(2), Vector2::new(3.0, 4.0));
+
+    let flat = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    let m3 = Matrix3::try_from(&flat[..]).unwrap();
+    assert_eq!(
+        m3,
+        Matrix3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+    );
+
+    assert!(Matrix3::try_from(&flat[..8]).is_err());
+    assert!(Matrix4::try_from(&flat[..]).is_err());
+}
+
+#[test]
+fn transform_point_affine_matches_transform_point() {
+    let rotation = Quaternion::new_axis_angle(Vector3::new(1.0, -1.0, 2.0).normalized(), 0.9);
+    let matrices = [
+        Matrix4::IDENTITY,
+        Matrix4::new_translation(Vector3::new(1.0, -2.0, 3.0)),
+        Matrix4::from_trs(
+            Vector3::new(-4.0, 0.5, 2.0),
+            rotation,
+            Vector3::new(2.0, 1.0, 0.5),
+        ),
+    ];
+
+    for m in matrices {
+        for p in [
+            Vector3::ZERO,
+            Vector3::new(1.5, -0.5, 7.0),
+            Vector3::new(-3.0, 2.0, 0.1),
+        ] {
+            assert_approx!(m.transform_point_affine(p), m.transform_point(p));
+        }
+    }
+}
+
+#[test]
+fn perspective_projection_with_typed_angle() {
+    let raw = Matrix4::new_perspective_projection(FRAC_PI_2, 1.5, 0.1, 50.0);
+
+    let rad = Matrix4::new_perspective_projection_angle(
+        Angle::<f64, Rad>::new(FRAC_PI_2),
+        1.5,
+        0.1,
+        50.0,
+    );
+    let deg =
+        Matrix4::new_perspective_projection_angle(Angle::<f64, Deg>::new(90.0), 1.5, 0.1, 50.0);
+
+    assert_approx!(rad, raw);
+    assert_approx!(deg, raw);
+}
+
+#[test]
+fn handedness_of_identity_and_reflection() {
+    let reflection = Matrix3::new_reflection(Vector3::new(0.0, 1.0, 0.0));
+
+    assert!(Matrix3::<f64>::IDENTITY.is_right_handed());
+    assert!(!reflection.is_right_handed());
+
+    assert!(Matrix4::<f64>::IDENTITY.is_right_handed());
+    assert!(!Matrix4::new_diagonal(Vector4::new(1.0, -1.0, 1.0, 1.0)).is_right_handed());
+}
+
+#[test]
+fn hadamard_with_identity_keeps_diagonal() {
+    let m = Matrix3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(
+        m.hadamard(&Matrix3::IDENTITY),
+        Matrix3::new_diagonal(Vector3::new(1.0, 5.0, 9.0))
+    );
+
+    let m = Matrix2::from([[-1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(
+        m.hadamard(&Matrix2::IDENTITY),
+        Matrix2::from([[-1.0, 0.0], [0.0, 4.0]])
+    );
+}
+
+#[test]
+fn without_translation_transforms_directions() {
+    let rotation = Quaternion::new_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.7);
+    let m = Matrix4::from_trs(Vector3::new(5.0, -3.0, 2.0), rotation, Vector3::ONE);
+    let d = Vector3::new(1.0, 2.0, -1.0);
+
+    let direction = m * d.extend(0.0);
+    assert_approx!(
+        m.without_translation().transform_point(d),
+        direction.truncate()
+    );
+    assert_approx!(m.rotation_only() * d, rotation.into_matrix3() * d);
+}
+
+#[test]
+fn rotation_only_of_mirrored_matrix_is_proper() {
+    let rotation = Quaternion::new_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.7);
+    let m = Matrix4::from_trs(Vector3::ZERO, rotation, Vector3::new(-2.0, 1.0, 3.0));
+
+    let r = m.rotation_only();
+    assert!(r.is_right_handed());
+    assert_approx!(r.det(), 1.0);
+    assert_approx!(r * r.transposed(), Matrix3::IDENTITY);
+}
+
+#[test]
+fn lerp_between_scale_matrices() {
+    let a = Matrix4::new_diagonal(Vector4::new(1.0, 2.0, 4.0, 1.0));
+    let b = Matrix4::new_diagonal(Vector4::new(3.0, 2.0, 0.0, 1.0));
+
+    assert_approx!(a.lerp(&b, 0.0), a);
+    assert_approx!(
+        a.lerp(&b, 0.25),
+        Matrix4::new_diagonal(Vector4::new(1.5, 2.0, 3.0, 1.0))
+    );
+    assert_approx!(a.lerp(&b, 1.0), b);
+
+    let a = Matrix3::new_diagonal(Vector3::new(1.0, 1.0, 1.0));
+    let b = Matrix3::new_diagonal(Vector3::new(2.0, 4.0, 8.0));
+    assert_approx!(
+        a.lerp(&b, 0.5),
+        Matrix3::new_diagonal(Vector3::new(1.5, 2.5, 4.5))
+    );
+}
+
+#[test]
+fn fov_planes_round_trip() {
+    let m = Matrix4::<f64>::from_fov_planes(1.1, 1.6, 0.5, 40.0);
+    let [left, _, _, top, near, far] = m.frustum_planes(DepthRange::NegativeOneToOne);
+
+    // Planes go through the origin at the slope of the half angles, depth planes cross `-Z`
+    // at the distance of `d / c`.
+    let f = top.y / top.z;
+    let fov_y = 2.0 * (1.0 / f).atan();
+    let aspect = f / (-left.x / left.z);
+    let (near, far) = (near.w / near.z, far.w / far.z);
+
+    assert_approx!(fov_y, 1.1);
+    assert_approx!(aspect, 1.6);
+    assert_approx!(near, 0.5);
+    assert_approx!(far, 40.0, 1e-9);
+    assert_approx!(Matrix4::from_fov_planes(fov_y, aspect, near, far), m);
+}
+
+#[test]
+fn reflection_flips_normal_component() {
+    let normal = Vector3::new(1.0, 2.0, -2.0).normalized();
+    let r = Matrix3::new_reflection(normal);
+    assert_approx!(r * r, Matrix3::IDENTITY);
+
+    let v = Vector3::new(0.5, -1.0, 3.0);
+    let (along, tangential) = (normal * v.dot(normal), v - normal * v.dot(normal));
+    assert_approx!(r * v, tangential - along);
+
+    let normal = Vector2::new(3.0, 4.0).normalized();
+    let r = Matrix2::new_reflection(normal);
+    assert_approx!(r * r, Matrix2::IDENTITY);
+    assert_approx!(r * normal, -normal);
+    assert_approx!(r * Vector2::new(-4.0, 3.0), Vector2::new(-4.0, 3.0));
+}
+
+#[test]
+fn permutation_reorders_components() {
+    let v = Vector3::new(10.0, 20.0, 30.0);
+
+    assert_eq!(
+        Matrix3::permutation([2, 0, 1]) * v,
+        Vector3::new(30.0, 10.0, 20.0)
+    );
+    assert_eq!(Matrix3::<f64>::permutation([0, 1, 2]), Matrix3::IDENTITY);
+}
+
+#[test]
+#[should_panic(expected = "Invalid permutation")]
+fn permutation_rejects_duplicates() {
+    let _ = Matrix3::<f64>::permutation([0, 2, 2]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid permutation")]
+fn permutation_rejects_out_of_range() {
+    let _ = Matrix3::<f64>::permutation([0, 1, 3]);
+}
+
+#[test]
+fn look_at_handedness() {
+    let (eye, target, up) = (
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(4.0, 2.0, -1.0),
+        Vector3::Y,
+    );
+
+    let rh = Matrix4::new_look_at_handed(eye, target, up, Handedness::Right);
+    let lh = Matrix4::new_look_at_handed(eye, target, up, Handedness::Left);
+
+    assert_approx!(rh.transform_point(eye), Vector3::ZERO);
+    assert_approx!(lh.transform_point(eye), Vector3::ZERO);
+    assert_approx!(rh.transform_point(target), Vector3::new(0.0, 0.0, -5.0));
+    assert_approx!(lh.transform_point(target), Vector3::new(0.0, 0.0, 5.0));
+
+    assert_approx!(Matrix4::new_look_at(eye, target, up), rh);
+}
+
+#[test]
+fn model_look_at_points_forward_at_target() {
+    let (position, target) = (Vector3::new(1.0, 0.0, 2.0), Vector3::new(4.0, 4.0, 2.0));
+    let m = Matrix4::new_model_look_at(position, target, Vector3::Z);
+
+    let forward = (m * Vector4::new(0.0, 0.0, -1.0, 0.0)).truncate();
+    assert_approx!(forward, (target - position).normalized());
+    assert_approx!(m.transform_point(Vector3::new(0.0, 0.0, -5.0)), target);
+    assert_approx!(m.transform_point(Vector3::ZERO), position);
+}
+
+#[test]
+fn generated_constants() {
+    assert_eq!(
+        Matrix2::<f64>::IDENTITY * Matrix2::IDENTITY,
+        Matrix2::IDENTITY
+    );
+    assert_eq!(
+        Matrix3::<f64>::IDENTITY * Matrix3::IDENTITY,
+        Matrix3::IDENTITY
+    );
+    assert_eq!(
+        Matrix4::<f64>::IDENTITY * Matrix4::IDENTITY,
+        Matrix4::IDENTITY
+    );
+
+    assert_eq!(Matrix2::<f64>::ZERO.hadamard(&Matrix2::ONE), Matrix2::ZERO);
+    assert_eq!(Matrix3::<f64>::ONE.map(|x| x * 2.0), Matrix3::ONE * 2.0);
+    assert_eq!(Matrix4::<f64>::IDENTITY.diagonal(), Vector4::ONE);
+}
+
+#[test]
+fn transpose_in_place_chains() {
+    let original = Matrix3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+    let mut m = original;
+    let doubled = *m.transpose_in_place().transpose_in_place() * 2.0;
+    assert_eq!(m, original);
+    assert_eq!(doubled, original * 2.0);
+
+    let mut m = original;
+    assert_eq!(*m.transpose_in_place(), original.transposed());
+}
+
+#[test]
+fn transform_plane_by_translation() {
+    let xy = Vector4::new(0.0, 0.0, 1.0, 0.0);
+    let m = Matrix4::new_translation(Vector3::new(5.0, -2.0, 3.0));
+
+    let moved = m.transform_plane(xy);
+    assert_approx!(moved, Vector4::new(0.0, 0.0, 1.0, -3.0));
+    assert_approx!(moved.dot_point(Vector3::new(1.0, 1.0, 3.0)), 0.0);
+    assert_approx!(m.inversed().transform_plane_by_inverse(xy), moved);
+}
+
+#[test]
+fn similarity_transform_keeps_trace() {
+    let inertia =
+        Matrix3::from_symmetric(Vector3::new(2.0, 3.0, 5.0), Vector3::new(0.5, -1.0, 0.25));
+    let basis = Matrix3::from_axis_angle(Vector3::new(1.0, 2.0, 2.0).normalized(), 0.8);
+
+    let transformed = inertia.similarity_transform(&basis);
+    assert_approx!(transformed.trace(), inertia.trace());
+    assert_approx!(transformed.det(), inertia.det(), 1e-9);
+    assert!(transformed.is_symmetric(1e-12));
+}
+
+#[test]
+fn decompose_trs_round_trip() {
+    let translation = Vector3::<f64>::new(1.0, -2.0, 3.5);
+    let rotation = Quaternion::new_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalized(), 0.7);
+    let scale = Vector3::new(2.0, 0.5, 3.0);
+    let m = Matrix4::from_trs(translation, rotation, scale);
+
+    let (t, r, s) = m.decompose_trs().unwrap();
+    assert_approx!(t, translation);
+    assert_approx!(r.dot(rotation).abs(), 1.0);
+    assert_approx!(s, scale);
+    assert_approx!(Matrix4::from_trs(t, r, s), m);
+
+    let projection = Matrix4::new_perspective_projection(1.0, 1.5, 0.1, 100.0);
+    assert!(projection.decompose_trs().is_none());
+}
+
+#[test]
+fn from_symmetric_places_entries() {
+    let m = Matrix3::from_symmetric(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0));
+
+    assert!(m.is_symmetric(1e-12));
+    assert_eq!(
+        m,
+        Matrix3::from([[1.0, 4.0, 5.0], [4.0, 2.0, 6.0], [5.0, 6.0, 3.0]])
+    );
+}
+
+#[test]
+fn symmetrized_matrices_are_symmetric() {
+    let m2 = Matrix2::from([[1.0, 2.0], [-3.0, 4.0]]);
+    let m3 = Matrix3::from([[1.0, 2.0, 3.0], [-4.0, 5.0, 6.0], [7.0, 0.5, 9.0]]);
+    let m4 = Matrix4::from([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, -7.0, 8.0],
+        [9.0, 1.5, 2.0, 3.0],
+        [0.0, 4.0, -2.0, 1.0],
+    ]);
+
+    assert!(!m2.is_symmetric(1e-12));
+    assert!(!m3.is_symmetric(1e-12));
+    assert!(!m4.is_symmetric(1e-12));
+    assert!(m2.symmetrized().is_symmetric(1e-12));
+    assert!(m3.symmetrized().is_symmetric(1e-12));
+    assert!(m4.symmetrized().is_symmetric(1e-12));
+
+    let s2 = m2.symmetrized();
+    let s3 = m3.symmetrized();
+    let s4 = m4.symmetrized();
+    assert_eq!(s2.symmetrized(), s2);
+    assert_eq!(s3.symmetrized(), s3);
+    assert_eq!(s4.symmetrized(), s4);
+}
+
+#[test]
+fn product_folds_left_to_right() {
+    let projection = Matrix4::new_perspective_projection(1.0, 1.5, 0.1, 100.0);
+    let view = Matrix4::new_translation(Vector3::new(0.0, -1.0, -5.0));
+    let model = Matrix4::new_look_at(Vector3::new(1.0, 2.0, 3.0), Vector3::ZERO, Vector3::Y);
+
+    assert_approx!(
+        Matrix4::product(&[projection, view, model]),
+        projection * view * model,
+        1e-12
+    );
+    assert_eq!(Matrix4::<f64>::product(&[]), Matrix4::IDENTITY);
+}
+
+#[test]
+fn lerp_trs_midpoint_slerps_rotation() {
+    let axis = Vector3::<f64>::new(0.0, 1.0, 1.0).normalized();
+    let (from_r, to_r) = (
+        Quaternion::new_axis_angle(axis, 0.2),
+        Quaternion::new_axis_angle(axis, 2.6),
+    );
+    let from = Matrix4::from_trs(Vector3::new(1.0, 0.0, 0.0), from_r, Vector3::ONE);
+    let to = Matrix4::from_trs(
+        Vector3::new(3.0, 2.0, -4.0),
+        to_r,
+        Vector3::new(3.0, 1.0, 2.0),
+    );
+
+    let expected = Matrix4::from_trs(
+        Vector3::new(2.0, 1.0, -2.0),
+        from_r.slerp(to_r, 0.5),
+        Vector3::new(2.0, 1.0, 1.5),
+    );
+    assert_approx!(from.lerp_trs(&to, 0.5).unwrap(), expected, 1e-12);
+    assert_approx!(from.lerp_trs(&to, 0.0).unwrap(), from, 1e-12);
+    assert_approx!(from.lerp_trs(&to, 1.0).unwrap(), to, 1e-12);
+    assert_approx!(from.lerp_trs(&from, 0.3).unwrap(), from, 1e-12);
+}
+
+#[test]
+fn perspective_scales_x_by_aspect() {
+    let fov_y = FRAC_PI_2 / 1.5;
+    let f = 1.0 / (fov_y / 2.0).tan();
+
+    for aspect in [0.5, 1.0, 16.0 / 9.0] {
+        let m =
+            Matrix4::new_perspective_full(fov_y, aspect, 0.1, Some(50.0), DepthRange::ZeroToOne);
+        assert_approx!(m.row1.x, f / aspect);
+        assert_approx!(m.row2.y, f);
+    }
+}
+
+#[test]
+fn perspective_accepts_screen_aspect() {
+    let aspect = 1920.0 / 1080.0;
+    let f = 1.0 / (1.0f64 / 2.0).tan();
+
+    let m = Matrix4::new_perspective_projection(1.0, aspect, 0.1, 100.0);
+    assert_approx!(m.row1.x, f / aspect);
+}
+
+#[test]
+#[should_panic(expected = "Aspect ratio must be positive and finite")]
+fn perspective_rejects_zero_height_aspect() {
+    Matrix4::new_perspective_projection(1.0, 1920.0 / 0.0, 0.1, 100.0);
+}
+
+#[test]
+#[should_panic(expected = "Aspect ratio must be positive and finite")]
+fn perspective_rejects_negative_aspect() {
+    Matrix4::new_perspective_projection(1.0, -1.5, 0.1, 100.0);
+}
+
+#[test]
+#[should_panic(expected = "Vertical field of view must be in (0, pi) range")]
+fn perspective_rejects_fov_out_of_range() {
+    Matrix4::new_perspective_projection(PI, 1.5, 0.1, 100.0);
+}
+
+#[test]
+#[should_panic(expected = "Near plane must be positive")]
+fn perspective_rejects_non_positive_near() {
+    Matrix4::new_perspective_projection(1.0, 1.5, 0.0, 100.0);
+}
+
+#[test]
+#[should_panic(expected = "Far plane must be further than near plane")]
+fn perspective_rejects_far_before_near() {
+    Matrix4::new_perspective_projection(1.0, 1.5, 10.0, 1.0);
+}
+
+#[test]
+fn map_and_clamp_elements() {
+    let m = Matrix2::from([[-3.0, 0.5], [2.0, 7.0]]);
+
+    assert_eq!(m.map(f64::abs), Matrix2::from([[3.0, 0.5], [2.0, 7.0]]));
+    assert_eq!(m.map(|x| x * 2.0), m * 2.0);
+
+    let min = Matrix2::from([[-1.0, -1.0], [-1.0, 0.0]]);
+    let max = Matrix2::from([[1.0, 1.0], [1.0, 5.0]]);
+    assert_eq!(
+        m.clamp(&min, &max),
+        Matrix2::from([[-1.0, 0.5], [1.0, 5.0]])
+    );
+
+    let m3 = Matrix3::from([[-2.0, 0.0, 2.0], [3.0, -0.5, 0.5], [-4.0, 4.0, 1.0]]);
+    let (lo, hi) = (m3.map(|_| -1.0), m3.map(|_| 1.0));
+    assert_eq!(
+        m3.clamp(&lo, &hi),
+        Matrix3::from([[-1.0, 0.0, 1.0], [1.0, -0.5, 0.5], [-1.0, 1.0, 1.0]])
+    );
+}
+
+#[test]
+fn scale_about_keeps_pivot_fixed() {
+    let pivot2 = Vector2::new(3.0, -1.0);
+    let m3 = Matrix3::new_scale_about(Vector2::new(2.0, 0.5), pivot2);
+    assert_approx!(m3 * pivot2.extend(1.0), pivot2.extend(1.0));
+    assert_approx!(
+        m3 * Vector3::new(4.0, 1.0, 1.0),
+        Vector3::new(5.0, 0.0, 1.0)
+    );
+
+    let pivot3 = Vector3::new(1.0, 2.0, -3.0);
+    let m4 = Matrix4::new_scale_about(Vector3::new(3.0, 0.5, -1.0), pivot3);
+    assert_approx!(m4.transform_point_affine(pivot3), pivot3);
+    assert_approx!(
+        m4.transform_point_affine(Vector3::new(2.0, 4.0, -1.0)),
+        Vector3::new(4.0, 3.0, -5.0)
+    );
+}
+
+#[test]
+fn scalar_on_the_left() {
+    let m = Matrix3::<f64>::from([[1.0, 2.0, 3.0], [-4.0, 5.0, 6.0], [7.0, 0.5, 9.0]]);
+    assert_eq!(2.0 * m, m * 2.0);
+
+    let m = Matrix2::<f32>::from([[1.0, -2.0], [3.5, 4.0]]);
+    assert_eq!(3.0f32 * m, m * 3.0);
+
+    let m = Matrix4::<f32>::new_translation(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(0.5f32 * m, m * 0.5);
+}
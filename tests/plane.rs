@@ -0,0 +1,24 @@
+#[macro_use]
+mod common;
+
+use sath::{Plane, Vector3, Vector4};
+
+#[test]
+fn signed_distance_on_both_sides() {
+    let plane = Plane::from_point_normal(Vector3::new(0.0, 0.0, 2.0), Vector3::Z);
+
+    assert_approx!(plane.signed_distance(Vector3::new(1.0, -3.0, 5.0)), 3.0);
+    assert_approx!(plane.signed_distance(Vector3::new(4.0, 1.0, -1.0)), -3.0);
+    assert_approx!(plane.signed_distance(Vector3::new(7.0, 7.0, 2.0)), 0.0);
+
+    // Unnormalized coefficients give the same distances.
+    let scaled = Plane(Vector4::new(0.0, 0.0, 4.0, -8.0));
+    assert_approx!(scaled.signed_distance(Vector3::new(1.0, -3.0, 5.0)), 3.0);
+    assert_approx!(
+        scaled.project_point(Vector3::new(1.0, -3.0, 5.0)),
+        Vector3::new(1.0, -3.0, 2.0)
+    );
+
+    let ccw = Plane::from_points(Vector3::ZERO, Vector3::X, Vector3::Y);
+    assert!(ccw.signed_distance(Vector3::new(0.2, 0.3, 1.0)) > 0.0);
+}
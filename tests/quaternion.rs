@@ -0,0 +1,282 @@
+#[macro_use]
+mod common;
+
+use sath::{kabsch, Euler, EulerOrder, Matrix3, Matrix4, Quaternion, Rad, Vector3};
+use std::f64::consts::FRAC_PI_2;
+
+fn assert_same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) {
+    assert_approx!(a.dot(b).abs(), 1.0, 1e-9);
+}
+
+#[test]
+fn kabsch_recovers_rotation() {
+    let rotation = Quaternion::new_axis_angle(Vector3::new(1.0, 2.0, -0.5).normalized(), 1.3);
+    let translation = Vector3::new(4.0, -2.0, 7.5);
+
+    let from = [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+        Vector3::new(0.5, -1.0, 3.0),
+        Vector3::new(-2.0, 1.0, 1.0),
+    ];
+    let to = from.map(|p| p.rotated_by(rotation) + translation);
+
+    assert_same_rotation(kabsch(&from, &to).unwrap(), rotation);
+}
+
+#[test]
+fn kabsch_rejects_invalid_input() {
+    let points = [Vector3::<f64>::X, Vector3::Y];
+
+    assert!(kabsch::<f64>(&[], &[]).is_none());
+    assert!(kabsch(&points, &points[..1]).is_none());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not normalized")]
+fn slerp_rejects_unnormalized() {
+    let q = Quaternion::new_axis_angle(Vector3::<f64>::Z, 1.0);
+    let _ = (q * 2.0).slerp(Quaternion::IDENTITY, 0.5);
+}
+
+#[test]
+fn slerp_normalized_matches_slerp() {
+    let (a, b) = (
+        Quaternion::new_axis_angle(Vector3::<f64>::Z, 0.2),
+        Quaternion::new_axis_angle(Vector3::Z, 1.4),
+    );
+
+    let mid = (a * 3.0).slerp_normalized(b * 0.5, 0.5);
+    assert_approx!(mid, a.slerp(b, 0.5));
+    assert_approx!(mid, Quaternion::new_axis_angle(Vector3::Z, 0.8));
+}
+
+#[test]
+fn exp_and_ln_near_identity() {
+    assert_eq!(
+        Quaternion::<f64>::IDENTITY.ln(),
+        Quaternion::from_vector(Vector3::ZERO)
+    );
+    assert_eq!(
+        Quaternion::from_vector(Vector3::<f64>::ZERO).exp(),
+        Quaternion::IDENTITY
+    );
+
+    // For a tiny vector part `v`, `exp(v) ~ 1 + v` and `ln(1 + v) ~ v` to second order.
+    let v = Vector3::new(3e-9, -4e-9, 1e-9);
+    let q = Quaternion::from_vector(v).exp();
+    assert_approx!(q.vector, v, 1e-24);
+    assert_approx!(q.scalar, 1.0, 1e-16);
+    assert_approx!(Quaternion::new(1.0, v).ln().vector, v, 1e-24);
+}
+
+#[test]
+fn exp_inverts_ln() {
+    for q in [
+        Quaternion::new_axis_angle(Vector3::new(0.0, 0.6, 0.8), 2.5),
+        Quaternion::new_axis_angle(Vector3::<f64>::X, 1e-7) * 3.0,
+        Quaternion::new(-2.0, Vector3::new(0.5, 1.0, -0.25)),
+        Quaternion::new(0.5, Vector3::new(1e-10, 0.0, 0.0)),
+    ] {
+        assert_approx!(q.ln().exp(), q, 1e-12);
+    }
+}
+
+#[test]
+#[cfg(feature = "fast-math")]
+fn nlerp_fast_approximates_nlerp() {
+    let a = Quaternion::new_axis_angle(Vector3::<f64>::X, 0.1);
+    let b = Quaternion::new_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.5);
+
+    for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        assert_approx!(a.nlerp_fast(b, t), a.nlerp(b, t), 1e-6);
+    }
+}
+
+const ORDERS: [EulerOrder; 6] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+];
+
+#[test]
+fn from_euler_axes_single_axis() {
+    for order in ORDERS {
+        let yaw = Quaternion::from_euler_axes(0.7, 0.0, 0.0, order);
+        let pitch = Quaternion::from_euler_axes(0.0, 0.7, 0.0, order);
+        let roll = Quaternion::from_euler_axes(0.0, 0.0, 0.7, order);
+
+        assert_approx!(yaw, Quaternion::new_axis_angle(Vector3::Z, 0.7));
+        assert_approx!(pitch, Quaternion::new_axis_angle(Vector3::X, 0.7));
+        assert_approx!(roll, Quaternion::new_axis_angle(Vector3::Y, 0.7));
+    }
+}
+
+#[test]
+fn from_euler_axes_applies_order() {
+    let (yaw, pitch, roll) = (0.3, -1.1, 0.8);
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    let stepwise = v
+        .rotated_by(Quaternion::new_axis_angle(Vector3::X, pitch))
+        .rotated_by(Quaternion::new_axis_angle(Vector3::Y, roll))
+        .rotated_by(Quaternion::new_axis_angle(Vector3::Z, yaw));
+    let q = Quaternion::from_euler_axes(yaw, pitch, roll, EulerOrder::XYZ);
+
+    assert_approx!(v.rotated_by(q), stepwise);
+}
+
+#[test]
+fn from_euler_matches_closed_form() {
+    let angles = Euler::<Rad, f64>::new(0.3, -1.1, 0.8);
+    let half = angles / 2.0;
+
+    let expected = Quaternion::new(
+        half.pitch.cos() * half.roll.cos() * half.yaw.cos()
+            + half.pitch.sin() * half.roll.sin() * half.yaw.sin(),
+        Vector3::new(
+            half.pitch.sin() * half.roll.cos() * half.yaw.cos()
+                - half.pitch.cos() * half.roll.sin() * half.yaw.sin(),
+            half.pitch.cos() * half.roll.sin() * half.yaw.cos()
+                + half.pitch.sin() * half.roll.cos() * half.yaw.sin(),
+            half.pitch.cos() * half.roll.cos() * half.yaw.sin()
+                - half.pitch.sin() * half.roll.sin() * half.yaw.cos(),
+        ),
+    );
+
+    assert_approx!(Quaternion::from_euler(angles), expected);
+}
+
+#[test]
+fn orientation_distances() {
+    let a = Quaternion::new_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.4);
+    let b = Quaternion::new_axis_angle(Vector3::X, 1.1);
+
+    assert_approx!(a.geodesic_distance(a), 0.0, 1e-6);
+    assert_approx!(a.chordal_distance(a), 0.0);
+    assert_approx!(a.geodesic_distance(b), b.geodesic_distance(a));
+    assert_approx!(a.chordal_distance(b), b.chordal_distance(a));
+
+    let negated = a * -1.0;
+    assert_approx!(a.geodesic_distance(negated), 0.0, 1e-6);
+    assert_approx!(a.chordal_distance(negated), 0.0);
+
+    let c = Quaternion::new_axis_angle(Vector3::X, 0.5);
+    assert_approx!(Quaternion::IDENTITY.geodesic_distance(c), 0.5);
+}
+
+#[test]
+fn blend_close_rotations() {
+    let a = Quaternion::new_axis_angle(Vector3::Z, 0.2);
+    let b = Quaternion::new_axis_angle(Vector3::Z, 0.3);
+    let expected = Quaternion::new_axis_angle(Vector3::Z, 0.25);
+
+    assert_approx!(Quaternion::blend(&[(a, 1.0), (b, 1.0)]).unwrap(), expected);
+    // `-b` is the same rotation in the opposite hemisphere.
+    assert_approx!(
+        Quaternion::blend(&[(a, 1.0), (b * -1.0, 1.0)]).unwrap(),
+        expected
+    );
+
+    assert!(Quaternion::<f64>::blend(&[]).is_none());
+}
+
+#[test]
+fn angle_of_rotation() {
+    assert_eq!(Quaternion::<f64>::IDENTITY.angle(), 0.0);
+    assert_approx!(
+        Quaternion::new_axis_angle(Vector3::Y, FRAC_PI_2).angle(),
+        FRAC_PI_2
+    );
+    assert_approx!(
+        (Quaternion::new_axis_angle(Vector3::Y, FRAC_PI_2) * -1.0).angle(),
+        FRAC_PI_2
+    );
+}
+
+#[test]
+fn rotate_towards_caps_step() {
+    let from = Quaternion::new_axis_angle(Vector3::X, 0.2);
+    let target = Quaternion::new_axis_angle(Vector3::X, 1.2);
+
+    let step = from.rotate_towards(target, 0.3);
+    assert_approx!(step, Quaternion::new_axis_angle(Vector3::X, 0.5));
+    assert_approx!(step.angle_between(from), 0.3);
+
+    assert_eq!(from.rotate_towards(target, 1.5), target);
+    assert_eq!(from.rotate_towards(target, 1.0), target);
+}
+
+#[test]
+fn array_round_trip() {
+    let q = Quaternion::new(0.5, Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(q.to_array(), [1.0, 2.0, 3.0, 0.5]);
+    assert_eq!(Quaternion::from_array(q.to_array()), q);
+    assert_eq!(Quaternion::from_array([4.0, 5.0, 6.0, 7.0]).scalar, 7.0);
+}
+
+#[test]
+fn from_matrix4_ignores_scale() {
+    let rotation = Quaternion::new_axis_angle(Vector3::new(0.3, -1.0, 0.5).normalized(), 1.2);
+    let scaled = rotation.into_matrix3() * Matrix3::new_scale(Vector3::new(2.0, 0.5, 4.0));
+    let m = scaled.extend(Vector3::ZERO, Vector3::new(1.0, 2.0, 3.0), 1.0);
+
+    assert_same_rotation(Quaternion::from_matrix4(m), rotation);
+    assert_same_rotation(
+        Quaternion::from_matrix4(Matrix4::IDENTITY),
+        Quaternion::IDENTITY,
+    );
+}
+
+#[test]
+fn rotate_slice_matches_rotated_by() {
+    let q = Quaternion::new_axis_angle(Vector3::new(1.0, -2.0, 0.5).normalized(), 0.9);
+    let points = [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.5, -3.0, 2.0),
+        Vector3::new(-1.0, 4.0, 0.25),
+    ];
+
+    let mut out = [Vector3::ZERO; 3];
+    q.rotate_slice(&points, &mut out);
+    for (p, o) in points.iter().zip(&out) {
+        assert_approx!(*o, p.rotated_by(q), 1e-12);
+    }
+
+    let mut in_place = points;
+    q.rotate_slice_in_place(&mut in_place);
+    assert_approx!(in_place[1], out[1], 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn rotate_slice_length_mismatch() {
+    let mut out = [Vector3::ZERO; 1];
+    Quaternion::<f64>::IDENTITY.rotate_slice(&[Vector3::X, Vector3::Y], &mut out);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_in_cone_stays_within_angle() {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(0x5eed);
+    let axis = Vector3::new(1.0, -2.0, 0.5).normalized();
+    let max_angle = 0.4;
+
+    let mut mean = Vector3::ZERO;
+    for _ in 0..1000 {
+        let dir = axis.rotated_by(Quaternion::random_in_cone(axis, max_angle, &mut rng));
+        assert!(axis.angle_to(dir) <= max_angle + 1e-9);
+        mean += dir;
+    }
+
+    // Directions are spread around the axis, so their mean points along it.
+    assert!(axis.angle_to(mean) < max_angle / 10.0);
+}
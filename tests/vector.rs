@@ -0,0 +1,521 @@
+#[macro_use]
+mod common;
+
+use sath::{compute_tangent, fresnel, path_lerp, Axis3, Matrix4, Vector2, Vector3, Vector4};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn dominant_axis_of_cube_faces() {
+    let faces = [
+        (Vector3::new(0.9, 0.2, -0.3), Vector3::X),
+        (Vector3::new(-0.9, 0.2, -0.3), -Vector3::X),
+        (Vector3::new(0.1, 0.7, 0.5), Vector3::Y),
+        (Vector3::new(0.1, -0.7, 0.5), -Vector3::Y),
+        (Vector3::new(0.4, -0.2, 0.8), Vector3::Z),
+        (Vector3::new(0.4, -0.2, -0.8), -Vector3::Z),
+    ];
+
+    for (v, axis) in faces {
+        assert_eq!(v.dominant_axis(), axis);
+    }
+}
+
+#[test]
+fn dominant_axis_of_zero() {
+    assert_eq!(Vector2::<f64>::ZERO.dominant_axis(), Vector2::Y);
+    assert_eq!(Vector3::<f64>::ZERO.dominant_axis(), Vector3::Z);
+    assert_eq!(Vector4::<f64>::ZERO.dominant_axis(), Vector4::W);
+}
+
+#[test]
+fn splat_matches_same() {
+    assert_eq!(Vector3::splat(2.0), Vector3::same(2.0));
+    assert_eq!(Vector4::splat(-1.5), Vector4::same(-1.5));
+}
+
+#[test]
+fn cross_2d_sign_and_area() {
+    let (a, b) = (Vector2::<f64>::new(3.0, 0.0), Vector2::new(1.0, 2.0));
+
+    assert!(a.cross(b) > 0.0);
+    assert!(b.cross(a) < 0.0);
+    assert_eq!(a.perp_dot(b), a.cross(b));
+
+    // Parallelogram with base 3 and height 2.
+    assert_approx!(a.cross(b).abs(), 6.0);
+    assert_approx!(b.cross(a).abs(), 6.0);
+}
+
+#[test]
+fn direction_and_length_reconstructs_vector() {
+    let v = Vector3::new(3.0, -4.0, 12.0);
+
+    let (dir, length) = v.direction_and_length();
+    assert_approx!(length, 13.0);
+    assert_approx!(dir.magnitude(), 1.0);
+    assert_approx!(dir * length, v);
+
+    let mut w = Vector2::new(-6.0, 8.0);
+    let length = w.normalize_and_length();
+    assert_approx!(length, 10.0);
+    assert_approx!(w * length, Vector2::new(-6.0, 8.0));
+}
+
+#[test]
+fn homogenize_round_trip() {
+    let p = Vector3::new(1.5, -2.0, 4.0);
+    let m = Matrix4::new_translation(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(p.homogenize().dehomogenize(), p);
+    assert_approx!(
+        (m * p.homogenize()).dehomogenize(),
+        Vector3::new(2.5, 0.0, 7.0)
+    );
+    assert_approx!(
+        Vector4::new(2.0, 4.0, -6.0, 2.0).dehomogenize(),
+        Vector3::new(1.0, 2.0, -3.0)
+    );
+}
+
+#[test]
+fn rotation_around_pivot() {
+    let pivot = Vector2::new(1.0, 1.0);
+    assert_approx!(
+        Vector2::new(3.0, 1.0).rotated_around(pivot, FRAC_PI_2),
+        Vector2::new(1.0, 3.0)
+    );
+
+    let mut p = Vector2::new(1.0, 0.0);
+    p.rotate_around(pivot, FRAC_PI_2);
+    assert_approx!(p, Vector2::new(2.0, 1.0));
+
+    let pivot = Vector3::new(1.0, 2.0, 3.0);
+    let p = Vector3::new(2.0, 2.0, 5.0);
+    assert_approx!(
+        p.rotated_around(pivot, Vector3::Z, FRAC_PI_2),
+        Vector3::new(1.0, 3.0, 5.0)
+    );
+}
+
+#[test]
+fn dot_point_is_plane_signed_distance() {
+    // Plane `y = 2` with normal pointing up.
+    let plane = Vector4::new(0.0, 1.0, 0.0, -2.0);
+
+    assert_approx!(plane.dot_point(Vector3::new(5.0, 3.5, -1.0)), 1.5);
+    assert_approx!(plane.dot_point(Vector3::new(-2.0, 0.0, 4.0)), -2.0);
+    assert_approx!(plane.dot_point(Vector3::new(1.0, 2.0, 1.0)), 0.0);
+}
+
+#[test]
+fn tangent_of_axis_aligned_uvs() {
+    let (p0, p1, p2) = (
+        Vector3::ZERO,
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 3.0, 0.0),
+    );
+    let (uv0, uv1, uv2) = (Vector2::ZERO, Vector2::X, Vector2::Y);
+
+    let (tangent, bitangent) = compute_tangent(p0, p1, p2, uv0, uv1, uv2);
+    assert_approx!(tangent, Vector3::new(2.0, 0.0, 0.0));
+    assert_approx!(bitangent, Vector3::new(0.0, 3.0, 0.0));
+
+    let (tangent, bitangent) = compute_tangent(p0, p1, p2, uv0, uv0, uv0);
+    assert_approx!(tangent, Vector3::X);
+    assert_approx!(bitangent, Vector3::Y);
+}
+
+#[test]
+fn component_wise_division() {
+    let v = Vector3::<f64>::new(4.0, -9.0, 1.0);
+    let d = Vector3::new(2.0, 3.0, 0.5);
+
+    assert_eq!(v / d, Vector3::new(2.0, -3.0, 2.0));
+    assert_eq!(v.try_div_components(d), Some(Vector3::new(2.0, -3.0, 2.0)));
+    assert_eq!(v.try_div_components(Vector3::new(2.0, 0.0, 1.0)), None);
+    assert!((v / Vector3::new(2.0, 0.0, 1.0)).y.is_infinite());
+}
+
+#[test]
+fn fresnel_reflectance() {
+    let eta: f64 = 1.0 / 1.5;
+    let r0 = ((1.0 - eta) / (1.0 + eta)).powi(2);
+
+    assert_approx!(fresnel(1.0, eta), r0);
+    assert_approx!(fresnel(0.0, eta), 1.0);
+    assert_approx!(fresnel(1e-3, eta), 1.0, 1e-2);
+    assert!(fresnel(0.2, eta) > fresnel(0.8, eta));
+}
+
+#[test]
+fn wedge_matches_cross() {
+    let (a, b) = (Vector2::new(1.5, -2.0), Vector2::new(0.5, 3.0));
+    assert_eq!(a.wedge(b), a.cross(b));
+    assert_eq!(a.wedge(b), a.perp_dot(b));
+
+    let (a, b) = (Vector3::new(1.0, 2.0, 3.0), Vector3::new(-2.0, 0.5, 4.0));
+    assert_eq!(a.wedge(b), a.cross(b));
+    assert_eq!(a.wedge(b), -b.wedge(a));
+}
+
+#[test]
+fn decompose_along_axis() {
+    let v = Vector3::new(3.0, -1.0, 2.0);
+    let axis = Vector3::new(1.0, 1.0, 0.0);
+
+    let (parallel, perpendicular) = v.decompose_along(axis);
+    assert_approx!(parallel + perpendicular, v);
+    assert_approx!(perpendicular.dot(axis), 0.0);
+    assert_approx!(parallel.cross(axis), Vector3::ZERO);
+    assert_approx!(parallel, Vector3::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn lp_norms() {
+    let v = Vector3::new(3.0, -4.0, 12.0);
+
+    assert_approx!(v.norm(2.0), v.magnitude());
+    assert_approx!(v.norm(1.0), 19.0);
+    assert_approx!(Vector4::new(1.0, -2.0, 0.5, -0.25).norm(1.0), 3.75);
+    assert_approx!(v.norm(64.0), 12.0, 1e-1);
+}
+
+#[test]
+fn saturate_clamps_colors() {
+    let color = Vector3::new(-0.5, 0.25, 1.75);
+    assert_eq!(color.saturate(), Vector3::new(0.0, 0.25, 1.0));
+
+    let color = Vector4::new(2.0, -1.0, 0.0, 1.0);
+    assert_eq!(color.saturate(), Vector4::new(1.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn inverse_lerp_round_trip() {
+    let (a, b) = (Vector3::new(1.0, -2.0, 0.5), Vector3::new(4.0, 2.0, -1.5));
+
+    assert_approx!(a.inverse_lerp(b, a.lerp(b, 0.3)), 0.3);
+    assert_approx!(a.inverse_lerp(b, a), 0.0);
+    assert_approx!(a.inverse_lerp(b, b), 1.0);
+}
+
+#[test]
+fn slerp_direction_between_directions() {
+    let (a, b) = (Vector3::X, Vector3::Y);
+    assert_approx!(
+        a.slerp_direction(b, 0.5),
+        Vector3::new(1.0, 1.0, 0.0).normalized()
+    );
+    assert_approx!(a.slerp_direction(b, 1.0), b);
+
+    // Magnitude of `self` is kept.
+    assert_approx!((a * 3.0).slerp_direction(b, 0.25).magnitude(), 3.0);
+
+    let almost_opposite = Vector3::new(-1.0, 1e-7, 0.0).normalized();
+    for t in [0.0, 0.3, 0.5, 1.0] {
+        let v = a.slerp_direction(almost_opposite, t);
+        assert_approx!(v.magnitude(), 1.0);
+        assert_approx!(v.angle_to(a), t * a.angle_to(almost_opposite), 1e-6);
+    }
+}
+
+#[test]
+fn signum_of_components() {
+    let v = Vector4::<f64>::new(0.0, -0.0, 2.5, -3.0);
+
+    assert_eq!(v.signum(), Vector4::new(1.0, -1.0, 1.0, -1.0));
+    assert_eq!(v.signum_or_zero(), Vector4::new(0.0, 0.0, 1.0, -1.0));
+    assert!(!v.signum_or_zero().y.is_sign_negative());
+}
+
+#[test]
+fn distance_to_line_and_segment() {
+    let (a, b) = (Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0));
+
+    // Closest point is in the middle of the segment.
+    let p = Vector3::new(2.0, 3.0, 0.0);
+    assert_approx!(p.distance_to_line(a, b), 3.0);
+    assert_approx!(p.distance_to_segment(a, b), 3.0);
+
+    // Closest point on the line is past `b`.
+    let p = Vector3::new(7.0, 0.0, 4.0);
+    assert_approx!(p.distance_to_line(a, b), 4.0);
+    assert_approx!(p.distance_to_segment(a, b), 5.0);
+
+    assert_approx!(
+        Vector2::new(3.0, 4.0).distance_to_segment(Vector2::ZERO, Vector2::ZERO),
+        5.0
+    );
+}
+
+#[test]
+fn horizontal_reductions_match_elements() {
+    let v2 = Vector2::new(-1.5, 4.0);
+    let v3 = Vector3::new(2.0, -7.0, 0.5);
+    let v4 = Vector4::new(3.0, 9.0, -2.0, 1.0);
+
+    assert_eq!(
+        (v2.horizontal_max(), v2.horizontal_min()),
+        (v2.max_element(), v2.min_element())
+    );
+    assert_eq!(
+        (v3.horizontal_max(), v3.horizontal_min()),
+        (v3.max_element(), v3.min_element())
+    );
+    assert_eq!(
+        (v4.horizontal_max(), v4.horizontal_min()),
+        (v4.max_element(), v4.min_element())
+    );
+    assert_eq!((v4.horizontal_max(), v4.horizontal_min()), (9.0, -2.0));
+    assert_eq!((v3.sum(), v3.product()), (-4.5, -7.0));
+}
+
+#[test]
+fn integer_conversions() {
+    let v = Vector4::new(-1.7, -0.5, 0.5, 2.5);
+
+    assert_eq!(v.as_i32_array(), [-1, 0, 0, 2]);
+    assert_eq!(v.floor_to_i32(), [-2, -1, 0, 2]);
+    assert_eq!(v.round_to_i32(), [-2, -1, 1, 3]);
+
+    let v = Vector3::new(f64::NAN, 1e20, -1e20);
+    assert_eq!(v.as_i32_array(), [0, i32::MAX, i32::MIN]);
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytes_round_trip() {
+    use sath::{bytes_as_slice, slice_as_bytes};
+
+    let points = [Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(-4.0, 5.5, 0.0)];
+    let bytes = slice_as_bytes(&points);
+    assert_eq!(bytes.len(), 2 * 3 * 4);
+    assert_eq!(&bytes[4..8], &2.0f32.to_ne_bytes());
+    assert_eq!(bytes_as_slice::<Vector3<f32>>(bytes), &points);
+
+    let matrices = [Matrix4::<f32>::IDENTITY];
+    assert_eq!(
+        bytes_as_slice::<Matrix4<f32>>(slice_as_bytes(&matrices)),
+        &matrices
+    );
+}
+
+#[test]
+fn projection_onto_axes() {
+    let v = Vector3::<f64>::new(3.0, -4.0, 5.0);
+
+    assert_approx!(v.projected_onto(Vector3::Y), Vector3::new(0.0, -4.0, 0.0));
+    assert_approx!(
+        v.projected_onto(Vector3::Y * 7.0),
+        Vector3::new(0.0, -4.0, 0.0)
+    );
+    assert_approx!(
+        v.projected_onto_unchecked(Vector3::Z),
+        Vector3::new(0.0, 0.0, 5.0)
+    );
+    assert_eq!(v.projected_onto(Vector3::ZERO), Vector3::ZERO);
+    assert_eq!(Vector3::<f64>::ZERO.try_normalized(), None);
+}
+
+#[test]
+fn spherical_midpoint_bisects_angle() {
+    let a = Vector3::<f64>::new(1.0, 2.0, -0.5).normalized();
+    let b = Vector3::<f64>::new(-0.3, 0.4, 1.0).normalized();
+    let mid = a.spherical_midpoint(b);
+
+    assert_approx!(mid.magnitude(), 1.0);
+    assert_approx!(a.angle_to(mid), b.angle_to(mid));
+    assert_approx!(a.angle_to(mid) * 2.0, a.angle_to(b));
+    assert_approx!(mid, a.slerp(b, 0.5));
+}
+
+#[test]
+fn signed_angle_to_in_plane() {
+    let a = Vector2::<f64>::new(1.0, 0.5);
+    let b = Vector2::<f64>::new(-0.5, 2.0);
+
+    let angle = a.signed_angle_to(b);
+    assert!(angle > 0.0);
+    assert_approx!(b.signed_angle_to(a), -angle);
+    assert_approx!(angle, a.arc_angle_to(b));
+    assert_approx!(b.signed_angle_to(a) + 2.0 * PI, b.arc_angle_to(a));
+    assert_approx!(Vector2::<f64>::X.signed_angle_to(Vector2::Y), FRAC_PI_2);
+}
+
+#[test]
+fn reflect_uses_surface_normal() {
+    let v = Vector2::<f64>::new(1.0, -2.0);
+
+    assert_approx!(v.reflect(Vector2::Y), Vector2::new(1.0, 2.0));
+    assert_approx!(v.reflect(Vector2::X), Vector2::new(-1.0, -2.0));
+    assert_approx!(v.reflect_across_axis(Vector2::X), Vector2::new(1.0, 2.0));
+    assert_approx!(
+        v.reflect_across_axis(Vector2::Y * 3.0),
+        Vector2::new(-1.0, -2.0)
+    );
+
+    let n = Vector2::new(1.0, 1.0).normalized();
+    assert_approx!(v.reflect(n), -v.reflect_across_axis(n));
+
+    let ray = Vector3::<f64>::new(1.0, -1.0, 1.0);
+    assert_approx!(ray.reflect_all(&[Vector3::X, Vector3::Y, Vector3::Z]), -ray);
+}
+
+#[test]
+fn normalized_or_falls_back_for_zero() {
+    let fallback = Vector3::<f64>::Z;
+
+    assert_eq!(Vector3::ZERO.normalized_or(fallback), fallback);
+    assert_approx!(
+        Vector3::new(3.0, 0.0, 4.0).normalized_or(fallback),
+        Vector3::new(0.6, 0.0, 0.8)
+    );
+    assert_approx!(
+        Vector2::<f64>::new(0.0, -2.0).normalized_or(Vector2::X),
+        -Vector2::Y
+    );
+}
+
+#[test]
+fn smooth_damp_converges_without_overshoot() {
+    let target = Vector3::<f64>::new(10.0, -5.0, 2.0);
+    let mut position = Vector3::ZERO;
+    let mut velocity = Vector3::ZERO;
+    let mut distance = position.distance_to(target);
+
+    for _ in 0..600 {
+        position = position.smooth_damp(target, &mut velocity, 0.3, 1.0 / 60.0);
+
+        let next = position.distance_to(target);
+        assert!(
+            next <= distance,
+            "moved away from target: {next} > {distance}"
+        );
+        assert!((target - Vector3::ZERO).dot(target - position) >= 0.0);
+        distance = next;
+    }
+
+    assert_approx!(position, target, 1e-6);
+    assert_approx!(velocity, Vector3::ZERO, 1e-6);
+}
+
+#[test]
+fn total_cmp_sorts_with_nan() {
+    let mut points = [
+        Vector2::new(1.0, f64::NAN),
+        Vector2::new(f64::NAN, 0.0),
+        Vector2::new(1.0, -2.0),
+        Vector2::new(-0.0, 3.0),
+        Vector2::new(0.0, 3.0),
+    ];
+    points.sort_by(|a, b| a.total_cmp(b));
+
+    assert_eq!(
+        points[0].to_bits_array(),
+        Vector2::new(-0.0, 3.0).to_bits_array()
+    );
+    assert_eq!(
+        points[1].to_bits_array(),
+        Vector2::new(0.0, 3.0).to_bits_array()
+    );
+    assert_eq!(points[2], Vector2::new(1.0, -2.0));
+    assert!(points[3].x == 1.0 && points[3].y.is_nan());
+    assert!(points[4].x.is_nan());
+    assert!(points
+        .windows(2)
+        .all(|w| w[0].total_cmp(&w[1]) != std::cmp::Ordering::Greater));
+}
+
+#[test]
+fn axis3_get_and_set() {
+    let mut v = Vector3::<f64>::new(1.0, 2.0, 3.0);
+
+    for (axis, value) in [(Axis3::X, 1.0), (Axis3::Y, 2.0), (Axis3::Z, 3.0)] {
+        assert_eq!(v.get(axis), value);
+        assert_eq!(v.get(axis), v.dot(axis.unit_vector()));
+    }
+
+    v.set(Axis3::X, -1.0);
+    assert_eq!(v, Vector3::new(-1.0, 2.0, 3.0));
+    v.set(Axis3::Y, -2.0);
+    assert_eq!(v, Vector3::new(-1.0, -2.0, 3.0));
+    v.set(Axis3::Z, -3.0);
+    assert_eq!(v, Vector3::new(-1.0, -2.0, -3.0));
+}
+
+#[test]
+fn path_lerp_by_arc_length() {
+    let points = [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(1.0, 3.0, 0.0),
+    ];
+
+    assert_eq!(path_lerp(&points, 0.0), Some(points[0]));
+    assert_eq!(path_lerp(&points, 1.0), Some(points[2]));
+    assert_approx!(
+        path_lerp(&points, 0.5).unwrap(),
+        Vector3::new(1.0, 1.0, 0.0)
+    );
+    assert_approx!(
+        path_lerp(&points, 0.25).unwrap(),
+        Vector3::new(1.0, 0.0, 0.0)
+    );
+
+    assert_eq!(path_lerp(&points[..1], 0.5), None);
+    assert_eq!(path_lerp::<f64>(&[], 0.5), None);
+}
+
+#[test]
+fn component_wise_mul_and_div() {
+    let a = Vector2::<f64>::new(2.0, -3.0);
+    let b = Vector2::new(4.0, 0.5);
+    assert_eq!(a * b, Vector2::new(8.0, -1.5));
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, a * b);
+    c /= b;
+    assert_eq!(c, a);
+
+    let mut v = Vector4::<f64>::new(1.0, -2.0, 0.0, 6.0);
+    v *= Vector4::new(3.0, 2.0, 5.0, 0.5);
+    assert_eq!(v, Vector4::new(3.0, -4.0, 0.0, 3.0));
+
+    // Division by a zero component follows float semantics.
+    v /= Vector4::new(1.0, 0.0, 0.0, 3.0);
+    assert_eq!(v.x, 3.0);
+    assert_eq!(v.y, f64::NEG_INFINITY);
+    assert!(v.z.is_nan());
+    assert_eq!(v.w, 1.0);
+}
+
+#[test]
+fn scalar_on_the_left() {
+    let v = Vector3::<f64>::new(1.0, -2.0, 3.5);
+    assert_eq!(2.0 * v, v * 2.0);
+
+    let v = Vector4::<f32>::new(1.0, -2.0, 3.5, 0.25);
+    assert_eq!(2.0f32 * v, v * 2.0);
+
+    let v = Vector2::<f32>::new(-1.5, 4.0);
+    assert_eq!(0.5f32 * v, v * 0.5);
+}
+
+#[test]
+fn swizzles_reorder_components() {
+    let v2 = Vector2::<f64>::new(1.0, 2.0);
+    assert_eq!(v2.yx(), Vector2::new(2.0, 1.0));
+
+    let v3 = Vector3::<f64>::new(1.0, 2.0, 3.0);
+    assert_eq!(v3.zyx(), Vector3::new(3.0, 2.0, 1.0));
+    assert_eq!(v3.yzx(), Vector3::new(2.0, 3.0, 1.0));
+    assert_eq!(v3.zx(), Vector2::new(3.0, 1.0));
+
+    let v4 = Vector4::<f64>::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v4.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(v4.ywzx(), Vector4::new(2.0, 4.0, 3.0, 1.0));
+    assert_eq!(v4.xyz(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(v4.wxz(), Vector3::new(4.0, 1.0, 3.0));
+    assert_eq!(v4.wy(), Vector2::new(4.0, 2.0));
+    assert_eq!(v4.xyzw(), v4);
+}